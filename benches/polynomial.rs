@@ -0,0 +1,60 @@
+//! Benchmarks of polynomial evaluation during share generation.
+//!
+//! Horner's method, as used by [`Polynomial::evaluate`],
+//! is compared with the naive evaluation raising x to every power,
+//! for the high thresholds where the difference matters.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use shamir_secret_sharing::field::Field;
+use shamir_secret_sharing::gf256::Gf256;
+use shamir_secret_sharing::polynomial::Polynomial;
+use shamir_secret_sharing::prime::Mersenne61;
+
+/// Evaluates the polynomial at `x` by raising `x` to every power.
+fn naive<F: Field>(polynomial: &Polynomial<F>, x: &F) -> F {
+    polynomial
+        .coefficients()
+        .iter()
+        .enumerate()
+        .fold(F::zero(), |y, (power, coefficient)| {
+            y + coefficient.clone() * x.pow(power as u64)
+        })
+}
+
+/// Benchmarks the evaluation of a random polynomial of every threshold
+/// at the x-coordinates of as many shares.
+fn evaluation<F: Field>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(format!("evaluation/{name}"));
+    let mut rng = rand::thread_rng();
+    for threshold in [16, 64, 255] {
+        let polynomial = Polynomial::random(F::one(), threshold - 1, &mut rng);
+        let x: Vec<F> = (1..=threshold as u64).map(F::from_u64).collect();
+
+        group.bench_with_input(BenchmarkId::new("naive", threshold), &x, |b, x| {
+            b.iter(|| {
+                x.iter()
+                    .map(|x| naive(black_box(&polynomial), x))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("horner", threshold), &x, |b, x| {
+            b.iter(|| {
+                x.iter()
+                    .map(|x| black_box(&polynomial).evaluate(x))
+                    .collect::<Vec<_>>()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn gf256(c: &mut Criterion) {
+    evaluation::<Gf256>(c, "gf256");
+}
+
+fn mersenne61(c: &mut Criterion) {
+    evaluation::<Mersenne61>(c, "mersenne61");
+}
+
+criterion_group!(benches, gf256, mersenne61);
+criterion_main!(benches);
@@ -0,0 +1,139 @@
+//! Barycentric Lagrange interpolation.
+//!
+//! The Lagrange basis polynomial of the j-th point is
+//! ℓⱼ(x) = ℓ(x) wⱼ / (x - xⱼ), where ℓ(x) is the product of (x - xₘ) over all points
+//! and the barycentric weight wⱼ is the inverse of the product of (xⱼ - xₘ) over m ≠ j.
+//! The weights only depend on the x-coordinates: once computed in O(n²),
+//! every basis at a new x is evaluated in O(n),
+//! instead of recomputing the full products of the Lagrange form.
+
+use crate::error::Error;
+use crate::field::Field;
+
+/// The barycentric weights of a set of distinct x-coordinates.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Barycentric<F> {
+    x: Vec<F>,
+    weights: Vec<F>,
+}
+
+impl<F: Field> Barycentric<F> {
+    /// Computes the barycentric weights of the x-coordinates.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if there are no x-coordinates,
+    /// or if two of them are the same.
+    pub fn new(x: &[F]) -> Result<Self, Error> {
+        if x.is_empty() {
+            return Err(Error::NoShares);
+        }
+
+        let weights = x
+            .iter()
+            .enumerate()
+            .map(|(j, x_j)| {
+                x.iter()
+                    .enumerate()
+                    .filter(|&(m, _)| m != j)
+                    .fold(F::one(), |product, (_, x_m)| {
+                        product * (x_j.clone() - x_m.clone())
+                    })
+                    .inverse()
+                    .ok_or(Error::DuplicateShares)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Barycentric {
+            x: x.to_vec(),
+            weights,
+        })
+    }
+
+    /// Returns the x-coordinates the weights were computed for.
+    pub fn x(&self) -> &[F] {
+        &self.x
+    }
+
+    /// Returns the barycentric weights, one for every x-coordinate.
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// Evaluates every Lagrange basis polynomial at `x`.
+    ///
+    /// At one of the x-coordinates, its basis is one and the others are zero.
+    pub fn bases(&self, x: &F) -> Vec<F> {
+        if let Some(j) = self.x.iter().position(|x_j| x_j == x) {
+            let mut bases = vec![F::zero(); self.x.len()];
+            bases[j] = F::one();
+            return bases;
+        }
+
+        let product = self
+            .x
+            .iter()
+            .fold(F::one(), |product, x_m| product * (x.clone() - x_m.clone()));
+        self.x
+            .iter()
+            .zip(&self.weights)
+            .map(|(x_j, weight)| {
+                // x differs from every x-coordinate, hence the difference is invertible
+                product.clone() * weight.clone() / (x.clone() - x_j.clone())
+            })
+            .collect()
+    }
+
+    /// Evaluates at `x` the polynomial interpolating the y-coordinates,
+    /// given in the same order as the x-coordinates.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there is not one y-coordinate for every x-coordinate.
+    pub fn evaluate(&self, y: &[F], x: &F) -> F {
+        assert_eq!(y.len(), self.x.len(), "one y-coordinate per x-coordinate");
+        self.bases(x)
+            .into_iter()
+            .zip(y)
+            .fold(F::zero(), |value, (basis, y)| value + basis * y.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf256::Gf256;
+    use crate::polynomial::Polynomial;
+
+    #[test]
+    fn evaluates_as_the_interpolating_polynomial() {
+        let polynomial = Polynomial::new([7, 3, 200].map(Gf256).to_vec());
+        let x: Vec<Gf256> = [1, 2, 3].map(Gf256).to_vec();
+        let y: Vec<Gf256> = x.iter().map(|x| polynomial.evaluate(x)).collect();
+        let barycentric = Barycentric::new(&x).unwrap();
+        for x in (0..=u8::MAX).map(Gf256) {
+            assert_eq!(barycentric.evaluate(&y, &x), polynomial.evaluate(&x));
+        }
+    }
+
+    #[test]
+    fn bases_at_an_x_coordinate_select_its_point() {
+        let barycentric = Barycentric::new(&[1, 2, 3].map(Gf256)).unwrap();
+        assert_eq!(
+            barycentric.bases(&Gf256(2)),
+            [Gf256::ZERO, Gf256::ONE, Gf256::ZERO]
+        );
+    }
+
+    #[test]
+    fn rejects_no_or_duplicate_x_coordinates() {
+        assert!(matches!(
+            Barycentric::<Gf256>::new(&[]),
+            Err(Error::NoShares)
+        ));
+        assert!(matches!(
+            Barycentric::new(&[Gf256(1), Gf256(1)]),
+            Err(Error::DuplicateShares)
+        ));
+    }
+}
@@ -0,0 +1,321 @@
+//! Berlekamp-Welch decoding, to identify incorrect shares and reconstruct the secret without them.
+//!
+//! The shares of a polynomial P of degree lower than `k` form a Reed-Solomon codeword,
+//! and `n` shares, at most e = ⌊(n - k) / 2⌋ of which are incorrect, still determine P.
+//! Berlekamp-Welch decoding looks for the monic error locator E of degree e,
+//! whose roots include the x-coordinates of the incorrect shares,
+//! and for Q = PE of degree lower than k + e, such that Q(x) = yE(x) at every share (x, y).
+//! These n equations are linear in the coefficients of Q and E,
+//! hence solved by Gaussian elimination, and P is the quotient of Q by E.
+//! The incorrect shares are the ones that do not lie on P,
+//! and [`combine`] reconstructs the secret from the decoded polynomials.
+
+use std::collections::BTreeSet;
+
+use crate::barycentric::Barycentric;
+use crate::error::Error;
+use crate::field::Field;
+use crate::polynomial::Polynomial;
+use crate::validation::{validate_byte_field, validate_shares, validate_x_coordinates};
+use crate::{Secret, Share};
+
+/// Decodes the polynomial of degree lower than `k` through all but at most ⌊(n - k) / 2⌋
+/// of the `n` points, along with the indices of the points that do not lie on it.
+///
+/// ## Errors
+///
+/// Returns an error if `k` is zero or greater than the number of points,
+/// if an x-coordinate is zero or the same as another one,
+/// or if too many points are incorrect to decode the polynomial.
+pub fn decode<F: Field>(points: &[(F, F)], k: usize) -> Result<(Polynomial<F>, Vec<usize>), Error> {
+    if k == 0 {
+        return Err(Error::ZeroThreshold);
+    }
+    if k > points.len() {
+        return Err(Error::ThresholdAboveShares {
+            threshold: k,
+            shares: points.len(),
+        });
+    }
+    let x: Vec<F> = points.iter().map(|(x, _)| x.clone()).collect();
+    validate_x_coordinates(&x)?;
+
+    // the unknowns are the k + e coefficients of Q, then the e lower coefficients of E,
+    // whose leading coefficient is one, and every point (x, y) is the equation
+    // Q(x) - y(E(x) - xᵉ) = yxᵉ
+    let errors = (points.len() - k) / 2;
+    let columns = k + 2 * errors;
+    let rows = points
+        .iter()
+        .map(|(x, y)| {
+            let powers: Vec<F> = (0..=k + errors)
+                .scan(F::one(), |power, _| {
+                    let current = power.clone();
+                    *power = power.clone() * x.clone();
+                    Some(current)
+                })
+                .collect();
+            powers[..k + errors]
+                .iter()
+                .cloned()
+                .chain(
+                    powers[..errors]
+                        .iter()
+                        .map(|power| -(y.clone() * power.clone())),
+                )
+                .chain(std::iter::once(y.clone() * powers[errors].clone()))
+                .collect()
+        })
+        .collect();
+    let solution = solve(rows, columns).ok_or(Error::Undecodable)?;
+
+    let locator: Vec<F> = solution[k + errors..]
+        .iter()
+        .cloned()
+        .chain(std::iter::once(F::one()))
+        .collect();
+    let polynomial =
+        Polynomial::new(divide(&solution[..k + errors], &locator).ok_or(Error::Undecodable)?);
+
+    let incorrect: Vec<usize> = points
+        .iter()
+        .enumerate()
+        .filter(|(_, (x, y))| polynomial.evaluate(x) != *y)
+        .map(|(i, _)| i)
+        .collect();
+    if incorrect.len() > errors {
+        return Err(Error::Undecodable);
+    }
+    Ok((polynomial, incorrect))
+}
+
+/// Identifies the shares that are inconsistent with the majority of the shares,
+/// returning their indices in `shares`, whose labels may tell who submitted them.
+///
+/// With exactly as many shares as the threshold, every set of shares is consistent,
+/// hence no share is identified.
+/// With more shares, every element of the secret is decoded from all the shares,
+/// and a share is inconsistent if it does not lie on one of the decoded polynomials.
+/// Identifying e incorrect shares among `n` of a threshold `k` split requires n - k ≥ 2e,
+/// hence at least `k + 2` shares: with only `k + 1`, an incorrect share is detected
+/// but any of the shares could be the incorrect one.
+///
+/// ## Errors
+///
+/// Returns an error if the shares are inconsistent, see [`validate_shares`],
+/// if their threshold is zero,
+/// or if some shares are incorrect but too few are correct to identify them.
+pub fn inconsistent_shares<F: Field>(shares: &[Share<F>]) -> Result<Vec<usize>, Error> {
+    validate_shares(shares)?;
+    find_inconsistent_shares(shares)
+}
+
+/// Combines shares to reconstruct the secret, even if up to ⌊(n - k) / 2⌋ of the `n` shares
+/// of a threshold `k` split are incorrect, along with the indices of the incorrect shares.
+///
+/// Every element of the secret is the constant term of its polynomial decoded from all the shares,
+/// hence the secret is right even if different elements are incorrect in different shares,
+/// and the padding and the fingerprint of the shares are handled as in [`crate::combine`].
+///
+/// ## Errors
+///
+/// Returns an error if the field cannot hold every byte,
+/// if the shares are inconsistent, see [`validate_shares`],
+/// if too many of them are incorrect to identify them, see [`inconsistent_shares`],
+/// or if the reconstructed secret is invalid, see [`crate::combine`].
+pub fn combine<F: Field>(shares: &[Share<F>]) -> Result<(Secret, Vec<usize>), Error> {
+    validate_byte_field::<F>()?;
+    validate_shares(shares)?;
+    let (secret, incorrect) = decode_secret(shares)?;
+    Ok((crate::to_secret(shares, secret)?, incorrect))
+}
+
+/// Identifies the inconsistent shares among shares already validated,
+/// see [`inconsistent_shares`].
+pub(crate) fn find_inconsistent_shares<F: Field>(shares: &[Share<F>]) -> Result<Vec<usize>, Error> {
+    if shares.len() <= shares[0].threshold() {
+        return Ok(Vec::new());
+    }
+    decode_secret(shares).map(|(_, incorrect)| incorrect)
+}
+
+/// Decodes every element of the secret from shares already validated,
+/// along with the indices of the shares that do not lie on one of its polynomials.
+fn decode_secret<F: Field>(shares: &[Share<F>]) -> Result<(Vec<F>, Vec<usize>), Error> {
+    let k = shares[0].threshold();
+    if k == 0 {
+        return Err(Error::ZeroThreshold);
+    }
+
+    // checking the other shares against the polynomials through the first k shares
+    // is cheaper than decoding, which is only needed if one of them does not lie on them
+    let x: Vec<F> = shares.iter().map(|share| share.x().clone()).collect();
+    let barycentric = Barycentric::new(&x[..k])?;
+    let bases: Vec<Vec<F>> = x[k..].iter().map(|x| barycentric.bases(x)).collect();
+    let secret_bases = barycentric.bases(&F::zero());
+    let evaluate = |bases: &[F], i: usize| {
+        shares[..k]
+            .iter()
+            .zip(bases)
+            .fold(F::zero(), |value, (share, basis)| {
+                value + share.y()[i].clone() * basis.clone()
+            })
+    };
+
+    let mut secret = Vec::with_capacity(shares[0].y().len());
+    let mut inconsistent = BTreeSet::new();
+    for i in 0..shares[0].y().len() {
+        let consistent = shares[k..]
+            .iter()
+            .zip(&bases)
+            .all(|(share, bases)| evaluate(bases, i) == share.y()[i]);
+        if consistent {
+            secret.push(evaluate(&secret_bases, i));
+        } else {
+            let points: Vec<(F, F)> = shares
+                .iter()
+                .map(|share| (share.x().clone(), share.y()[i].clone()))
+                .collect();
+            // the shares are known to be inconsistent, hence failing to decode them
+            // means that the incorrect ones cannot be identified
+            let (polynomial, incorrect) = decode(&points, k).map_err(|error| match error {
+                Error::Undecodable => Error::UnidentifiableShares,
+                error => error,
+            })?;
+            secret.push(polynomial.evaluate(&F::zero()));
+            inconsistent.extend(incorrect);
+        }
+    }
+    Ok((secret, inconsistent.into_iter().collect()))
+}
+
+/// Solves a linear system, given as the rows of its augmented matrix,
+/// by Gauss-Jordan elimination, with zero for every free unknown,
+/// or returns `None` if the system has no solution.
+fn solve<F: Field>(mut rows: Vec<Vec<F>>, columns: usize) -> Option<Vec<F>> {
+    let mut pivots = Vec::new();
+    for column in 0..columns {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..rows.len()).find(|&row| rows[row][column] != F::zero()) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+
+        let inverse = rows[rank][column].inverse()?;
+        let pivot_row: Vec<F> = rows[rank]
+            .iter()
+            .map(|value| value.clone() * inverse.clone())
+            .collect();
+        for (row, values) in rows.iter_mut().enumerate() {
+            if row != rank && values[column] != F::zero() {
+                let factor = values[column].clone();
+                for (value, pivot_value) in values.iter_mut().zip(&pivot_row) {
+                    *value = value.clone() - factor.clone() * pivot_value.clone();
+                }
+            }
+        }
+        rows[rank] = pivot_row;
+        pivots.push(column);
+    }
+
+    // the remaining rows are zero, and so must be their right-hand sides
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| row[columns] != F::zero())
+    {
+        return None;
+    }
+    let mut solution = vec![F::zero(); columns];
+    for (row, column) in pivots.into_iter().enumerate() {
+        solution[column] = rows[row][columns].clone();
+    }
+    Some(solution)
+}
+
+/// Divides a polynomial by a monic one, both given by their coefficients from the constant term up,
+/// or returns `None` if the remainder is not zero.
+fn divide<F: Field>(numerator: &[F], denominator: &[F]) -> Option<Vec<F>> {
+    let degree = denominator.len() - 1;
+    let mut remainder = numerator.to_vec();
+    let mut quotient = vec![F::zero(); numerator.len().saturating_sub(degree)];
+    for i in (0..quotient.len()).rev() {
+        let coefficient = remainder[i + degree].clone();
+        for (j, term) in denominator.iter().enumerate() {
+            remainder[i + j] = remainder[i + j].clone() - coefficient.clone() * term.clone();
+        }
+        quotient[i] = coefficient;
+    }
+    remainder
+        .iter()
+        .all(|value| *value == F::zero())
+        .then_some(quotient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf256::Gf256;
+    use crate::split;
+
+    /// Returns the share with the given bytes of its y-coordinates changed.
+    fn corrupt(share: &Share<Gf256>, bytes: &[usize]) -> Share<Gf256> {
+        let mut y = share.y().to_vec();
+        for &i in bytes {
+            y[i] += Gf256::from_u64(1);
+        }
+        Share::new(*share.x(), y, share.threshold(), share.total())
+    }
+
+    /// Returns the points of the polynomial through the given coefficients at x = 1, ..., n.
+    fn points(coefficients: &[u8], n: u8) -> (Polynomial<Gf256>, Vec<(Gf256, Gf256)>) {
+        let polynomial = Polynomial::new(coefficients.iter().copied().map(Gf256).collect());
+        let points = (1..=n)
+            .map(|x| (Gf256(x), polynomial.evaluate(&Gf256(x))))
+            .collect();
+        (polynomial, points)
+    }
+
+    #[test]
+    fn decodes_with_up_to_half_the_extra_points_incorrect() {
+        let (polynomial, mut points) = points(&[42, 7, 200], 9);
+        assert_eq!(decode(&points, 3).unwrap(), (polynomial.clone(), vec![]));
+        for e in 1..=3 {
+            points[2 * e].1 += Gf256(e as u8);
+            let incorrect: Vec<usize> = (1..=e).map(|i| 2 * i).collect();
+            assert_eq!(decode(&points, 3).unwrap(), (polynomial.clone(), incorrect));
+        }
+        points[1].1 += Gf256(1);
+        assert!(matches!(decode(&points, 3), Err(Error::Undecodable)));
+    }
+
+    #[test]
+    fn identifies_incorrect_shares_only_with_two_extra_shares() {
+        let mut shares = split(b"abcdefgh", 3, 5).unwrap();
+        shares[1] = corrupt(&shares[1], &[3]);
+        assert_eq!(inconsistent_shares(&shares).unwrap(), [1]);
+        assert!(matches!(
+            inconsistent_shares(&shares[..4]),
+            Err(Error::UnidentifiableShares)
+        ));
+        assert!(inconsistent_shares(&shares[1..4]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn combine_reconstructs_the_secret_without_the_incorrect_shares() {
+        let mut shares = split(b"abcdefgh", 3, 5).unwrap();
+        shares[1] = corrupt(&shares[1], &[0, 3]);
+        assert_eq!(combine(&shares).unwrap(), (b"abcdefgh".to_vec(), vec![1]));
+    }
+
+    #[test]
+    fn combine_decodes_bytes_corrupted_in_different_shares() {
+        let mut shares = split(b"abcdefgh", 3, 7).unwrap();
+        for (share, bytes) in [(0, [0]), (1, [0]), (2, [1]), (3, [1]), (4, [2])] {
+            shares[share] = corrupt(&shares[share], &bytes);
+        }
+        assert_eq!(
+            combine(&shares).unwrap(),
+            (b"abcdefgh".to_vec(), vec![0, 1, 2, 3, 4])
+        );
+    }
+}
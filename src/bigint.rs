@@ -0,0 +1,206 @@
+//! Shamir's Secret Sharing of big integers.
+//!
+//! Instead of sharing a secret byte by byte,
+//! the secret is a single arbitrary-precision integer,
+//! shared with one polynomial over the prime field of order 2⁵²¹ - 1,
+//! the textbook construction of the scheme.
+//!
+//! This module requires the `bigint` feature.
+
+// Division multiplies by the inverse.
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::sync::OnceLock;
+
+use num_bigint::{BigUint, RandBigInt};
+use rand::{CryptoRng, Rng, RngCore};
+
+use crate::error::Error;
+use crate::field::Field;
+use crate::{interpolate_at, split_elements, Share};
+
+/// The exponent of the Mersenne prime 2⁵²¹ - 1.
+const EXPONENT: usize = 521;
+
+/// Returns the Mersenne prime 2⁵²¹ - 1.
+fn modulus() -> &'static BigUint {
+    static MODULUS: OnceLock<BigUint> = OnceLock::new();
+    MODULUS.get_or_init(|| (BigUint::from(1u8) << EXPONENT) - 1u8)
+}
+
+/// An element of the prime field of order 2⁵²¹ - 1.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mersenne521(BigUint);
+
+impl Mersenne521 {
+    /// Creates an element from an integer, reduced modulo 2⁵²¹ - 1.
+    pub fn new(value: BigUint) -> Self {
+        Mersenne521(value % modulus())
+    }
+
+    /// Returns the canonical representative of the element, in `[0, 2⁵²¹ - 1)`.
+    pub fn value(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl fmt::Display for Mersenne521 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Add for Mersenne521 {
+    type Output = Mersenne521;
+
+    fn add(self, rhs: Mersenne521) -> Mersenne521 {
+        Mersenne521::new(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Mersenne521 {
+    type Output = Mersenne521;
+
+    fn sub(self, rhs: Mersenne521) -> Mersenne521 {
+        self + -rhs
+    }
+}
+
+impl Neg for Mersenne521 {
+    type Output = Mersenne521;
+
+    fn neg(self) -> Mersenne521 {
+        if self.0 == BigUint::ZERO {
+            self
+        } else {
+            Mersenne521(modulus() - self.0)
+        }
+    }
+}
+
+impl Mul for Mersenne521 {
+    type Output = Mersenne521;
+
+    fn mul(self, rhs: Mersenne521) -> Mersenne521 {
+        Mersenne521::new(self.0 * rhs.0)
+    }
+}
+
+impl Div for Mersenne521 {
+    type Output = Mersenne521;
+
+    /// Divides two elements.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Mersenne521) -> Mersenne521 {
+        self * rhs.inverse().expect("division by zero in GF(2^521 - 1)")
+    }
+}
+
+impl Field for Mersenne521 {
+    const MAX_SHARES: u64 = u64::MAX;
+
+    fn zero() -> Self {
+        Mersenne521(BigUint::ZERO)
+    }
+
+    fn one() -> Self {
+        Mersenne521(BigUint::from(1u8))
+    }
+
+    /// The multiplicative group has order p - 1, hence the inverse is a^(p - 2).
+    fn inverse(&self) -> Option<Self> {
+        if self.0 == BigUint::ZERO {
+            None
+        } else {
+            let exponent = modulus() - 2u8;
+            Some(Mersenne521(self.0.modpow(&exponent, modulus())))
+        }
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Mersenne521(rng.gen_biguint_below(modulus()))
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Mersenne521(BigUint::from(value))
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(&self.0).ok()
+    }
+}
+
+/// Splits a big integer secret into `n` shares, any `k` of which reconstruct it.
+///
+/// The secret is the constant term of a single polynomial over GF(2⁵²¹ - 1),
+/// hence every share holds a single y-coordinate.
+///
+/// ## Errors
+///
+/// Returns an error if the secret is not smaller than 2⁵²¹ - 1,
+/// or if `k` is zero or greater than `n`.
+pub fn split(secret: &BigUint, k: usize, n: usize) -> Result<Vec<Share<Mersenne521>>, Error> {
+    split_with_rng(secret, k, n, &mut rand::thread_rng())
+}
+
+/// Splits a big integer secret into `n` shares, any `k` of which reconstruct it,
+/// drawing the coefficients of the polynomial from the given random number generator.
+///
+/// See [`split`] for the details and errors.
+pub fn split_with_rng<R: CryptoRng + RngCore + ?Sized>(
+    secret: &BigUint,
+    k: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<Share<Mersenne521>>, Error> {
+    if secret >= modulus() {
+        return Err(Error::SecretTooLarge {
+            bound: "2⁵²¹ - 1"
+        });
+    }
+    split_elements(&[Mersenne521(secret.clone())], k, n, rng)
+}
+
+/// Combines shares to reconstruct a big integer secret.
+///
+/// ## Errors
+///
+/// Returns an error if the shares do not hold exactly one y-coordinate,
+/// or if they are inconsistent, see [`crate::interpolate_at`].
+pub fn combine(shares: &[Share<Mersenne521>]) -> Result<BigUint, Error> {
+    if shares.iter().any(|share| share.y().len() != 1) {
+        return Err(Error::LengthMismatch);
+    }
+    let mut secret = interpolate_at(shares, &Mersenne521::zero())?;
+    Ok(secret.remove(0).0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_up_to_the_modulus() {
+        for secret in [
+            BigUint::from(1_000_000_007u64),
+            BigUint::from(1u8) << 520,
+            modulus() - 1u8,
+        ] {
+            let shares = split(&secret, 3, 5).unwrap();
+            assert_eq!(combine(&shares[2..]).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    fn rejects_secrets_not_below_the_modulus() {
+        assert!(matches!(
+            split(modulus(), 3, 5),
+            Err(Error::SecretTooLarge { .. })
+        ));
+    }
+}
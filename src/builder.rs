@@ -0,0 +1,354 @@
+//! Fluent configuration of a split.
+//!
+//! A [`ShamirBuilder`] collects the parameters of a split,
+//! and [`ShamirBuilder::build`] validates them before any secret is shared,
+//! so that a [`Shamir`] can split any number of secrets.
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+use rand::rngs::ThreadRng;
+use rand::{CryptoRng, RngCore};
+
+use crate::error::Error;
+use crate::field::Field;
+use crate::fingerprint::Fingerprint;
+use crate::gf256::Gf256;
+use crate::padding::pad;
+use crate::validation::{
+    validate_block_size, validate_byte_field, validate_parameters, validate_secret,
+};
+use crate::{split_over_with_rng, Share};
+
+/// A builder for the parameters of a split over the field `F`, GF(2⁸) by default,
+/// drawing the coefficients of the polynomials from the random number generator `R`,
+/// the thread-local one by default.
+#[derive(Debug)]
+pub struct ShamirBuilder<F = Gf256, R = ThreadRng> {
+    threshold: Option<usize>,
+    shares: Option<usize>,
+    labels: Vec<String>,
+    fingerprint: bool,
+    block_size: Option<usize>,
+    rng: R,
+    field: PhantomData<F>,
+}
+
+impl ShamirBuilder {
+    /// Creates a builder over GF(2⁸), without any parameter set.
+    pub fn new() -> Self {
+        ShamirBuilder {
+            threshold: None,
+            shares: None,
+            labels: Vec::new(),
+            fingerprint: false,
+            block_size: None,
+            rng: rand::thread_rng(),
+            field: PhantomData,
+        }
+    }
+}
+
+/// Only builders with the thread-local random number generator can be cloned,
+/// as clones of another generator would draw the same coefficients.
+impl<F> Clone for ShamirBuilder<F> {
+    fn clone(&self) -> Self {
+        ShamirBuilder {
+            threshold: self.threshold,
+            shares: self.shares,
+            labels: self.labels.clone(),
+            fingerprint: self.fingerprint,
+            block_size: self.block_size,
+            rng: rand::thread_rng(),
+            field: PhantomData,
+        }
+    }
+}
+
+impl Default for ShamirBuilder {
+    fn default() -> Self {
+        ShamirBuilder::new()
+    }
+}
+
+impl<F: Field, R: CryptoRng + RngCore> ShamirBuilder<F, R> {
+    /// Sets the number of shares needed to reconstruct the secret.
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the number of shares to generate.
+    pub fn shares(mut self, shares: usize) -> Self {
+        self.shares = Some(shares);
+        self
+    }
+
+    /// Sets the field to share secrets over.
+    pub fn field<G: Field>(self) -> ShamirBuilder<G, R> {
+        ShamirBuilder {
+            threshold: self.threshold,
+            shares: self.shares,
+            labels: self.labels,
+            fingerprint: self.fingerprint,
+            block_size: self.block_size,
+            rng: self.rng,
+            field: PhantomData,
+        }
+    }
+
+    /// Sets the random number generator to draw the coefficients of the polynomials
+    /// and the salts of the fingerprints from.
+    ///
+    /// Any cryptographically secure generator can be used,
+    /// such as the operating system's or a hardware one.
+    pub fn rng<S: CryptoRng + RngCore>(self, rng: S) -> ShamirBuilder<F, S> {
+        ShamirBuilder {
+            threshold: self.threshold,
+            shares: self.shares,
+            labels: self.labels,
+            fingerprint: self.fingerprint,
+            block_size: self.block_size,
+            rng,
+            field: PhantomData,
+        }
+    }
+
+    /// Labels the shares, in order, for example with the names of their holders.
+    ///
+    /// There must be one label for every share.
+    pub fn labels<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Embeds a fingerprint of the secret in every share,
+    /// so that [`crate::combine`] verifies the reconstructed secret,
+    /// see the [`crate::fingerprint`] module.
+    pub fn fingerprint(mut self) -> Self {
+        self.fingerprint = true;
+        self
+    }
+
+    /// Pads every secret to a multiple of `block_size` bytes before splitting it,
+    /// so that the length of the shares only reveals the number of blocks,
+    /// see the [`crate::padding`] module.
+    pub fn padding(mut self, block_size: usize) -> Self {
+        self.block_size = Some(block_size);
+        self
+    }
+
+    /// Validates the parameters.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the threshold or the number of shares are not set,
+    /// if they are invalid, see [`validate_parameters`],
+    /// if the field cannot hold every byte, see [`validate_byte_field`],
+    /// if the shares are labeled but there is not one label for every share,
+    /// or if the block size of the padding is zero.
+    pub fn build(self) -> Result<Shamir<F, R>, Error> {
+        let threshold = self.threshold.ok_or(Error::MissingParameter("threshold"))?;
+        let shares = self
+            .shares
+            .ok_or(Error::MissingParameter("number of shares"))?;
+        validate_parameters::<F>(threshold, shares)?;
+        validate_byte_field::<F>()?;
+        if !self.labels.is_empty() && self.labels.len() != shares {
+            return Err(Error::LabelMismatch {
+                labels: self.labels.len(),
+                shares,
+            });
+        }
+        if let Some(block_size) = self.block_size {
+            validate_block_size(block_size)?;
+        }
+
+        Ok(Shamir {
+            threshold,
+            shares,
+            labels: self.labels,
+            fingerprint: self.fingerprint,
+            block_size: self.block_size,
+            rng: RefCell::new(self.rng),
+            field: PhantomData,
+        })
+    }
+}
+
+/// The validated parameters of a split over the field `F`,
+/// with the random number generator `R` to draw the coefficients of the polynomials from.
+#[derive(Debug)]
+pub struct Shamir<F = Gf256, R = ThreadRng> {
+    threshold: usize,
+    shares: usize,
+    labels: Vec<String>,
+    fingerprint: bool,
+    block_size: Option<usize>,
+    rng: RefCell<R>,
+    field: PhantomData<F>,
+}
+
+/// Only splits with the thread-local random number generator can be cloned,
+/// as clones of another generator would draw the same coefficients.
+impl<F> Clone for Shamir<F> {
+    fn clone(&self) -> Self {
+        Shamir {
+            threshold: self.threshold,
+            shares: self.shares,
+            labels: self.labels.clone(),
+            fingerprint: self.fingerprint,
+            block_size: self.block_size,
+            rng: RefCell::new(rand::thread_rng()),
+            field: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, R: CryptoRng + RngCore> Shamir<F, R> {
+    /// Returns the number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the number of shares to generate.
+    pub fn shares(&self) -> usize {
+        self.shares
+    }
+
+    /// Splits a secret into shares, drawing the coefficients of the polynomials
+    /// from the random number generator of the builder, see [`crate::split_over`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the secret is empty, the parameters are already validated.
+    pub fn split(&self, secret: &[u8]) -> Result<Vec<Share<F>>, Error> {
+        self.split_with_rng(secret, &mut *self.rng.borrow_mut())
+    }
+
+    /// Splits a secret into shares, drawing the coefficients of the polynomials
+    /// from the given random number generator instead, see [`crate::split_over_with_rng`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the secret is empty, the parameters are already validated.
+    pub fn split_with_rng<G: CryptoRng + RngCore + ?Sized>(
+        &self,
+        secret: &[u8],
+        rng: &mut G,
+    ) -> Result<Vec<Share<F>>, Error> {
+        let mut shares = match self.block_size {
+            Some(block_size) => {
+                // an empty secret is rejected, rather than padded to a whole block
+                validate_secret(secret)?;
+                let padded = pad(secret, block_size)?;
+                split_over_with_rng::<F, G>(&padded, self.threshold, self.shares, rng)?
+                    .into_iter()
+                    .map(Share::with_padding)
+                    .collect()
+            }
+            None => split_over_with_rng::<F, G>(secret, self.threshold, self.shares, rng)?,
+        };
+        if self.fingerprint {
+            let fingerprint = Fingerprint::new(secret, rng);
+            shares = shares
+                .into_iter()
+                .map(|share| share.with_fingerprint(fingerprint.clone()))
+                .collect();
+        }
+        if self.labels.is_empty() {
+            return Ok(shares);
+        }
+        let shares = shares
+            .into_iter()
+            .zip(&self.labels)
+            .map(|(share, label)| share.with_label(label.as_str()))
+            .collect();
+        Ok(shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::combine;
+    use crate::gf65536::Gf65536;
+    use crate::prime::Fp;
+
+    #[test]
+    fn round_trips_with_every_setting() {
+        let shamir = ShamirBuilder::new()
+            .threshold(2)
+            .shares(3)
+            .field::<Gf65536>()
+            .labels(["alice", "bob", "carol"])
+            .fingerprint()
+            .padding(16)
+            .build()
+            .unwrap();
+        let shares = shamir.split(b"secret").unwrap();
+        assert_eq!(shares[1].label(), Some("bob"));
+        assert_eq!(shares[1].y().len(), 16);
+        assert!(shares[1].is_padded() && shares[1].fingerprint().is_some());
+        assert_eq!(combine(&shares[1..]).unwrap(), b"secret");
+    }
+
+    #[test]
+    fn draws_from_the_given_rng() {
+        let split = |seed| {
+            ShamirBuilder::new()
+                .threshold(2)
+                .shares(3)
+                .rng(StdRng::seed_from_u64(seed))
+                .build()
+                .unwrap()
+                .split(b"secret")
+                .unwrap()
+        };
+        assert_eq!(split(1), split(1));
+        assert_ne!(split(1), split(2));
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(matches!(
+            ShamirBuilder::new().shares(3).build(),
+            Err(Error::MissingParameter("threshold"))
+        ));
+        assert!(matches!(
+            ShamirBuilder::new().threshold(4).shares(3).build(),
+            Err(Error::ThresholdAboveShares { .. })
+        ));
+        assert!(matches!(
+            ShamirBuilder::new()
+                .threshold(2)
+                .shares(3)
+                .padding(0)
+                .build(),
+            Err(Error::ZeroBlockSize)
+        ));
+        assert!(matches!(
+            ShamirBuilder::new()
+                .threshold(2)
+                .shares(3)
+                .labels(["alice"])
+                .build(),
+            Err(Error::LabelMismatch { .. })
+        ));
+        assert!(matches!(
+            ShamirBuilder::new()
+                .threshold(2)
+                .shares(3)
+                .field::<Fp<31>>()
+                .build(),
+            Err(Error::FieldTooSmall)
+        ));
+    }
+}
@@ -0,0 +1,928 @@
+//! Charts of polynomials, their shares and secrets.
+//!
+//! A [`ChartSpec`] describes a chart of a polynomial over the real numbers,
+//! with its shares and optionally its secret, reconstructed with [`crate::real`].
+//! [`render_to_string`] renders it as an SVG document
+//! and [`render_to_png_bytes`] as a PNG image, in memory,
+//! so that applications can embed the charts without writing files.
+//! A [`PlotSink`] writes the rendered charts to files, to memory or to any writer.
+//! The drawing functions are public as well, to compose other charts,
+//! such as the ones of the image generator.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Cursor, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+
+use image::{ImageFormat, RgbImage};
+use plotters::chart::SeriesAnno;
+use plotters::coord::types::RangedCoordf32;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+
+use crate::error::Error;
+use crate::real::{combine, Polynomial, Share};
+
+/// The width and height of the full charts, in pixels.
+pub const DIMENSIONS: (u32, u32) = (640, 480);
+/// The width and height of the compact charts, for thumbnails.
+pub const THUMBNAIL_DIMENSIONS: (u32, u32) = (320, 240);
+/// The width and height of the annotated charts, including their table of coefficients.
+pub const ANNOTATED_DIMENSIONS: (u32, u32) = (800, 480);
+/// The width of the table of coefficients beside the annotated charts.
+pub const TABLE_WIDTH: u32 = 160;
+
+/// The font of the chart text, DejaVu Sans, bundled so that the text is measured
+/// and rasterized the same on every machine, see `fonts/LICENSE`.
+const FONT: &[u8] = include_bytes!("../fonts/DejaVuSans.ttf");
+
+/// The colors of the charts, and the CSS custom properties that restyle them.
+pub const THEME: [(RGBColor, &str); 5] = [
+    (BLACK, "--sss-foreground"),
+    (WHITE, "--sss-background"),
+    (BLUE, "--sss-polynomial"),
+    (GREEN, "--sss-secret"),
+    (MAGENTA, "--sss-corrupted"),
+];
+
+/// The number of shares of the largest chart, whose colors can be restyled.
+pub const THEMED_SHARES: usize = 5;
+
+/// The level of detail a chart is rendered with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Full chart with legend and coordinate labels.
+    Full,
+    /// Compact chart without legend and coordinate labels,
+    /// and with thicker strokes, suitable for thumbnails.
+    Compact,
+    /// Full chart with a table of the polynomial coefficients
+    /// beside it, so that the chart is self-explanatory.
+    Annotated,
+}
+
+impl Mode {
+    /// The dimensions of the charts of this mode.
+    pub fn dimensions(self) -> (u32, u32) {
+        match self {
+            Mode::Full => DIMENSIONS,
+            Mode::Compact => THUMBNAIL_DIMENSIONS,
+            Mode::Annotated => ANNOTATED_DIMENSIONS,
+        }
+    }
+
+    /// The font size of the captions of this mode.
+    pub fn caption_size(self) -> u32 {
+        match self {
+            Mode::Full | Mode::Annotated => 32,
+            Mode::Compact => 20,
+        }
+    }
+
+    /// The stroke width of the lines of this mode.
+    pub fn stroke_width(self) -> u32 {
+        match self {
+            Mode::Full | Mode::Annotated => 1,
+            Mode::Compact => 3,
+        }
+    }
+}
+
+/// The description of a chart of a polynomial, its shares and the secret.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartSpec {
+    /// The title of the chart.
+    pub title: String,
+    /// The dimensions of the chart, in pixels.
+    pub dimensions: (u32, u32),
+    /// The range of the x-axis.
+    pub x_range: Range<f32>,
+    /// The range of the y-axis.
+    pub y_range: Range<f32>,
+    /// The polynomial to plot.
+    pub polynomial: Polynomial,
+    /// The string representation of the polynomial.
+    pub polynomial_str: String,
+    /// The x-coordinates of the shares.
+    pub shares_x: Vec<f32>,
+    /// Whether to plot the secret.
+    pub secret: bool,
+    /// The half-width and half-height of the region around the secret
+    /// magnified in an inset, if any, only drawn with the secret and unless the mode is compact.
+    pub inset: Option<(f32, f32)>,
+    /// The level of detail to render the chart with.
+    pub mode: Mode,
+}
+
+/// Registers the bundled font as the `sans-serif` family the chart text is drawn with.
+///
+/// The drawing functions of this module register it themselves,
+/// it only needs to be called before drawing text with plotters directly.
+pub fn register_font() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| {
+        let registered = plotters::style::register_font("sans-serif", FontStyle::Normal, FONT);
+        assert!(registered.is_ok(), "the bundled font is a TrueType font");
+    });
+}
+
+/// Renders a chart as an SVG document whose colors are themable, see [`apply_theme`].
+///
+/// ## Errors
+///
+/// Returns an error if the chart cannot be drawn.
+pub fn render_to_string(spec: &ChartSpec) -> Result<String, Error> {
+    render_svg(spec.dimensions, |root_area| draw_chart(root_area, spec))
+}
+
+/// Renders a chart as a PNG image, on a white background.
+///
+/// ## Errors
+///
+/// Returns an error if the chart cannot be drawn or encoded.
+pub fn render_to_png_bytes(spec: &ChartSpec) -> Result<Vec<u8>, Error> {
+    let (width, height) = spec.dimensions;
+    let mut buffer = vec![u8::MAX; width as usize * height as usize * 3];
+    {
+        let root_area =
+            BitMapBackend::with_buffer(&mut buffer, spec.dimensions).into_drawing_area();
+        draw_chart(&root_area, spec)?;
+        root_area.present()?;
+    }
+
+    let image = RgbImage::from_raw(width, height, buffer).expect("one pixel per 3 bytes");
+    let mut png = Cursor::new(Vec::new());
+    image
+        .write_to(&mut png, ImageFormat::Png)
+        .map_err(|error| Error::Plotting(Box::new(error)))?;
+    Ok(png.into_inner())
+}
+
+/// Renders an SVG document in memory, on the root drawing area of the given dimensions,
+/// and makes its colors themable, see [`apply_theme`].
+///
+/// ## Errors
+///
+/// Returns an error if the chart cannot be drawn.
+pub fn render_svg<F>(dimensions: (u32, u32), draw: F) -> Result<String, Error>
+where
+    F: FnOnce(&DrawingArea<SVGBackend, Shift>) -> Result<(), Error>,
+{
+    let mut svg = String::new();
+    {
+        let root_area = SVGBackend::with_string(&mut svg, dimensions).into_drawing_area();
+        draw(&root_area)?;
+        root_area.present()?;
+    }
+    Ok(apply_theme(&svg))
+}
+
+/// A destination for rendered charts, such as files, an in-memory buffer or the standard output,
+/// so that rendering a chart does not depend on where it is written to.
+pub trait PlotSink {
+    /// Writes a rendered chart, named after the given path.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the chart cannot be written.
+    fn write(&mut self, path: &Path, contents: &[u8]) -> Result<(), Error>;
+}
+
+/// Writes every chart to the file at its path.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FileSink;
+
+impl PlotSink for FileSink {
+    fn write(&mut self, path: &Path, contents: &[u8]) -> Result<(), Error> {
+        fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+/// Keeps every chart in memory, as the bytes written under its path.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemorySink {
+    charts: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+impl MemorySink {
+    /// Creates a sink without any chart.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the bytes of the chart written under the given path, if any.
+    pub fn get(&self, path: &Path) -> Option<&[u8]> {
+        self.charts.get(path).map(Vec::as_slice)
+    }
+
+    /// Returns the bytes of every chart, by path.
+    pub fn into_charts(self) -> BTreeMap<PathBuf, Vec<u8>> {
+        self.charts
+    }
+}
+
+impl PlotSink for MemorySink {
+    fn write(&mut self, path: &Path, contents: &[u8]) -> Result<(), Error> {
+        self.charts.insert(path.to_path_buf(), contents.to_vec());
+        Ok(())
+    }
+}
+
+/// Writes every chart, one after another and regardless of its path, to a writer,
+/// such as the standard output with `WriterSink(std::io::stdout())`.
+#[derive(Clone, Debug, Default)]
+pub struct WriterSink<W>(pub W);
+
+impl<W: Write> PlotSink for WriterSink<W> {
+    fn write(&mut self, _path: &Path, contents: &[u8]) -> Result<(), Error> {
+        self.0.write_all(contents)?;
+        self.0.flush()?;
+        Ok(())
+    }
+}
+
+/// Draws a chart with a polynomial, its shares and the secret on a drawing area.
+///
+/// ## Errors
+///
+/// Returns an error if the chart cannot be drawn.
+pub fn draw_chart<DB>(root_area: &DrawingArea<DB, Shift>, spec: &ChartSpec) -> Result<(), Error>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    register_font();
+    let ChartSpec {
+        title,
+        x_range,
+        y_range,
+        polynomial,
+        polynomial_str,
+        shares_x,
+        secret,
+        inset,
+        mode,
+        ..
+    } = spec;
+    let (secret, inset, mode) = (*secret, *inset, *mode);
+
+    root_area.fill(&TRANSPARENT)?;
+
+    let chart_area = draw_caption(&root_area.margin(5, 5, 5, 5), title, mode)?;
+    let chart_area = draw_coefficients(&chart_area, polynomial, mode)?;
+
+    let mut chart = ChartBuilder::on(&chart_area)
+        .x_label_area_size(35)
+        .y_label_area_size(40)
+        .build_cartesian_2d(x_range.clone(), y_range.clone())?;
+
+    draw_axes(&mut chart, shares_x.len() + secret as usize, mode)?;
+
+    // add the polynomial, shares and secret to the chart
+    draw_polynomial(
+        &mut chart,
+        polynomial,
+        polynomial_str,
+        x_range.clone(),
+        BLUE,
+        mode,
+    )?;
+    let shares = shares_of(polynomial, shares_x);
+    draw_shares(&mut chart, &shares, mode)?;
+    if secret {
+        draw_secret(&mut chart, &shares, "Secret", GREEN, mode)?;
+    }
+
+    draw_legend(&mut chart, SeriesLabelPosition::LowerRight, mode)?;
+
+    if let (true, Some(extent), false) = (secret, inset, mode == Mode::Compact) {
+        draw_inset(&chart, polynomial, &shares, extent, mode)?;
+    }
+
+    Ok(())
+}
+
+/// Draws an inset at the upper left of a chart, magnifying the region around the secret.
+/// The region spans the given half-width and half-height around (0, secret),
+/// and is outlined on the chart, with a line joining it to the inset.
+/// The inset is drawn over the chart, on an opaque background,
+/// with the polynomial and the secret, whose coordinates can then be read.
+pub fn draw_inset<DB>(
+    chart: &ChartContext<DB, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+    polynomial: &Polynomial,
+    shares: &[Share],
+    (half_width, half_height): (f32, f32),
+    mode: Mode,
+) -> Result<(), Error>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    register_font();
+    let secret = combine(shares)? as f32;
+    let x_range = -half_width..half_width;
+    let y_range = secret - half_height..secret + half_height;
+
+    // the inset is a nested drawing area of the plotting area, in pixels
+    let plotting_area = chart.plotting_area().strip_coord_spec();
+    let (width, height) = plotting_area.dim_in_pixel();
+    let (left, top) = (10, 10);
+    let (inset_width, inset_height) = (width * 2 / 5, height * 2 / 5);
+    let inset_area = plotting_area
+        .clone()
+        .shrink((left, top), (inset_width, inset_height));
+    inset_area.fill(&WHITE)?;
+
+    // map the corners of the region from chart coordinates to the pixels of the plotting area
+    let (base_x, base_y) = plotting_area.get_base_pixel();
+    let to_pixel = |x: f32, y: f32| {
+        let (x, y) = chart.backend_coord(&(x, y));
+        (x - base_x, y - base_y)
+    };
+    let region_upper_left = to_pixel(x_range.start, y_range.end);
+    let region_lower_right = to_pixel(x_range.end, y_range.start);
+    let inset_lower_right = (left + inset_width as i32 - 1, top + inset_height as i32 - 1);
+    plotting_area.draw(&Rectangle::new(
+        [region_upper_left, region_lower_right],
+        BLACK.stroke_width(1),
+    ))?;
+    plotting_area.draw(&PathElement::new(
+        vec![inset_lower_right, region_upper_left],
+        BLACK.mix(0.5),
+    ))?;
+
+    let mut inset = ChartBuilder::on(&inset_area)
+        .margin(5)
+        .x_label_area_size(20)
+        .y_label_area_size(30)
+        .build_cartesian_2d(x_range.clone(), y_range)?;
+    inset
+        .configure_mesh()
+        .x_labels(3)
+        .y_labels(3)
+        .disable_mesh()
+        .label_style(("sans-serif", 10))
+        .x_label_formatter(&|v| format!("{:.2}", v))
+        .y_label_formatter(&|v| format!("{:.1}", v))
+        .draw()?;
+    draw_polynomial(&mut inset, polynomial, "", x_range, BLUE, mode)?;
+    draw_points(&mut inset, vec![(0.0, secret)], GREEN.filled(), mode)?;
+    inset_area.draw(&Rectangle::new(
+        [(0, 0), (inset_width as i32 - 1, inset_height as i32 - 1)],
+        BLACK.stroke_width(1),
+    ))?;
+
+    Ok(())
+}
+
+/// Draws a caption at the top of a drawing area.
+/// The caption is wrapped at word boundaries so that every line fits
+/// within the width of the area, as measured by the backend's text metrics.
+/// Lines are centered and stacked from the top.
+/// Returns the remaining drawing area below the caption,
+/// so that the chart never collides with a long caption.
+pub fn draw_caption<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    caption: &str,
+    mode: Mode,
+) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+    register_font();
+    let style: TextStyle = ("sans-serif", mode.caption_size()).into_font().into();
+    let (max_width, _) = area.dim_in_pixel();
+
+    let mut lines: Vec<String> = Vec::new();
+    for word in caption.split_whitespace() {
+        match lines.last_mut() {
+            Some(line)
+                if area
+                    .estimate_text_size(&format!("{line} {word}"), &style)?
+                    .0
+                    <= max_width =>
+            {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+
+    lines
+        .iter()
+        .try_fold(area.clone(), |area, line| area.titled(line, style.clone()))
+}
+
+/// Draws the caption of a step of an animation in a band at the bottom of a drawing area.
+/// The caption is centered in the band, at a smaller size than the title,
+/// so that every frame places its caption consistently below the chart.
+/// Returns the remaining drawing area above the band.
+pub fn draw_step_caption<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    caption: &str,
+    mode: Mode,
+) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+    register_font();
+    let size = mode.caption_size() * 5 / 8;
+    let (width, height) = area.dim_in_pixel();
+    let (chart_area, band_area) = area.split_vertically(height.saturating_sub(size * 3 / 2));
+
+    let style =
+        TextStyle::from(("sans-serif", size).into_font()).pos(Pos::new(HPos::Center, VPos::Center));
+    let (_, band_height) = band_area.dim_in_pixel();
+    band_area.draw_text(caption, &style, (width as i32 / 2, band_height as i32 / 2))?;
+
+    Ok(chart_area)
+}
+
+/// Draws a table of polynomial coefficients at the right of a drawing area.
+/// The table is only drawn in annotated mode, with one row per term,
+/// from the highest power down to the constant term.
+/// Returns the remaining drawing area at the left of the table,
+/// or the whole drawing area in the other modes.
+pub fn draw_coefficients<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    polynomial: &Polynomial,
+    mode: Mode,
+) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+    register_font();
+    if mode != Mode::Annotated {
+        return Ok(area.clone());
+    }
+
+    let coefficients = polynomial.coefficients();
+    let (width, _) = area.dim_in_pixel();
+    let (chart_area, table_area) = area.split_horizontally(width.saturating_sub(TABLE_WIDTH));
+
+    let style: TextStyle = ("sans-serif", 15).into_font().into();
+    let row_height = 20;
+    let (left, top, right) = (10, 10, TABLE_WIDTH as i32 - 10);
+    let bottom = top + row_height * (coefficients.len() as i32 + 1) + 5;
+
+    table_area.draw(&Rectangle::new(
+        [(left, top), (right, bottom)],
+        BLACK.stroke_width(1),
+    ))?;
+    table_area.draw(&Text::new("Term", (left + 10, top + 5), &style))?;
+    table_area.draw(&Text::new("Coefficient", (left + 60, top + 5), &style))?;
+    table_area.draw(&PathElement::new(
+        vec![(left, top + row_height), (right, top + row_height)],
+        BLACK,
+    ))?;
+
+    for (row, (power, coefficient)) in coefficients.iter().enumerate().rev().enumerate() {
+        let y = top + row_height * (row as i32 + 1) + 5;
+        table_area.draw(&Text::new(term(power), (left + 10, y), &style))?;
+        table_area.draw(&Text::new(coefficient.to_string(), (left + 60, y), &style))?;
+    }
+
+    Ok(chart_area)
+}
+
+/// Returns the string representation of the term of a polynomial
+/// with the given power, using superscript digits.
+fn term(power: usize) -> String {
+    const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    match power {
+        0 => "1".to_string(),
+        1 => "x".to_string(),
+        _ => power
+            .to_string()
+            .chars()
+            .filter_map(|digit| digit.to_digit(10))
+            .fold("x".to_string(), |mut term, digit| {
+                term.push(SUPERSCRIPTS[digit as usize]);
+                term
+            }),
+    }
+}
+
+/// Draws the axes of a chart, labeled with whole numbers,
+/// with `x_labels` labels on the x-axis and 5 on the y-axis,
+/// and a vertical line at x = 0, where the secret lies.
+/// The chart is updated in place.
+pub fn draw_axes<'a, DB>(
+    chart: &mut ChartContext<'a, DB, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+    x_labels: usize,
+    mode: Mode,
+) -> Result<(), Error>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+{
+    register_font();
+    chart
+        .configure_mesh()
+        .x_labels(x_labels)
+        .y_labels(5)
+        .disable_mesh()
+        .x_label_formatter(&|v| format!("{:.0}", v))
+        .y_label_formatter(&|v| format!("{:.0}", v))
+        .draw()?;
+
+    let y_range = chart.y_range();
+    chart.draw_series(LineSeries::new(
+        vec![(0.0, y_range.start), (0.0, y_range.end)],
+        BLACK.stroke_width(mode.stroke_width()),
+    ))?;
+    Ok(())
+}
+
+/// Draws the legend of the labeled series of a chart at the given position,
+/// on a translucent background, unless the mode is compact.
+/// The legend must be drawn after the series, so that it lists all of them.
+pub fn draw_legend<'a, DB>(
+    chart: &mut ChartContext<'a, DB, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+    position: SeriesLabelPosition,
+    mode: Mode,
+) -> Result<(), Error>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+{
+    register_font();
+    if mode != Mode::Compact {
+        chart
+            .configure_series_labels()
+            .position(position)
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.8))
+            .legend_area_size(10)
+            .draw()?;
+    }
+    Ok(())
+}
+
+/// Draws a polynomial on a chart.
+/// The polynomial is drawn as a line.
+/// The chart is updated in place.
+/// The polynomial is labeled in the legend, drawn in the given color with
+/// the stroke width of the mode and stepsize of 1e-3.
+pub fn draw_polynomial<DB>(
+    chart: &mut ChartContext<DB, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+    polynomial: &Polynomial,
+    polynomial_str: &str,
+    x_range: Range<f32>,
+    color: RGBColor,
+    mode: Mode,
+) -> Result<(), Error>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let points: Vec<(f32, f32)> = x_range
+        .step(1e-3)
+        .values()
+        .map(|x| (x, evaluate(polynomial, x)))
+        .collect();
+    chart
+        .draw_series(LineSeries::new(
+            points.iter().copied(),
+            color.stroke_width(mode.stroke_width()),
+        ))?
+        .label(polynomial_str)
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], color.stroke_width(2)));
+    Ok(())
+}
+
+/// Draws the envelope of candidate polynomials on a chart.
+/// The envelope is drawn as a translucent band between the minimum and
+/// the maximum of the candidates at every x, clamped to the y-axis.
+/// The chart is updated in place.
+/// The band is labeled in the legend, drawn in blue with a stepsize of 1e-3.
+pub fn draw_band<DB>(
+    chart: &mut ChartContext<DB, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+    candidates: &[Polynomial],
+    x_range: Range<f32>,
+    y_range: Range<f32>,
+) -> Result<(), Error>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let (lower, upper): (Vec<_>, Vec<_>) = x_range
+        .step(1e-3)
+        .values()
+        .map(|x| {
+            let (min, max) = candidates
+                .iter()
+                .map(|candidate| evaluate(candidate, x))
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), y| {
+                    (min.min(y), max.max(y))
+                });
+            let clamp = |y: f32| y.clamp(y_range.start, y_range.end);
+            ((x, clamp(min)), (x, clamp(max)))
+        })
+        .unzip();
+    let outline: Vec<(f32, f32)> = lower.into_iter().chain(upper.into_iter().rev()).collect();
+
+    let style = BLUE.mix(0.2).filled();
+    chart
+        .draw_series(std::iter::once(Polygon::new(outline, style)))?
+        .label("Consistent polynomials")
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], style));
+    Ok(())
+}
+
+/// Draws shares on a chart.
+/// The shares are drawn as points, see [`draw_points`],
+/// each in its own color from [`share_color`].
+/// The chart is updated in place.
+/// The shares are labeled in the legend with the colors of the first three shares.
+pub fn draw_shares<'a, DB>(
+    chart: &mut ChartContext<'a, DB, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+    shares: &[Share],
+    mode: Mode,
+) -> Result<(), Error>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+{
+    for (index, share) in shares.iter().enumerate() {
+        let annotation = draw_points(chart, vec![point(share)], share_color(index + 1), mode)?;
+        if index == 0 {
+            // the legend shows the colors of the first three shares
+            annotation.label("Shares").legend(|(x, y)| {
+                EmptyElement::at((x, y))
+                    + Circle::new((1, 0), 2, share_color(1))
+                    + Circle::new((5, 0), 2, share_color(2))
+                    + Circle::new((9, 0), 2, share_color(3))
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the color of the share with the given index, counted from 1.
+/// The hue is the Fibonacci hash of the index, the fractional part of its product
+/// with the inverse of the golden ratio, which spreads consecutive indices around
+/// the arcs of the color wheel at least 30° away from the hues of the polynomial (blue),
+/// the secret (green) and the corrupted share (magenta), so that no share is mistaken for them.
+/// The same share has the same color in every chart and animation frame.
+pub fn share_color(index: usize) -> ShapeStyle {
+    const INVERSE_GOLDEN_RATIO: f64 = 0.618_033_988_749_895;
+    // the arcs from 330° to 90° and from 150° to 210°, as their start and length in degrees
+    const ARCS: [(f64, f64); 2] = [(330.0, 120.0), (150.0, 60.0)];
+    let mut offset = (index as f64 * INVERSE_GOLDEN_RATIO).fract() * 180.0;
+    let mut hue = 0.0;
+    for (start, length) in ARCS {
+        if offset < length {
+            hue = (start + offset) % 360.0;
+            break;
+        }
+        offset -= length;
+    }
+    let (r, g, b) = HSLColor(hue / 360.0, 0.8, 0.45).rgb();
+    RGBColor(r, g, b).filled()
+}
+
+/// Draws the secret on a chart.
+/// The secret is reconstructed from the shares and drawn as a point.
+/// The chart is updated in place.
+/// The secret is labeled in the legend, drawn in the given color with a size of 5.
+pub fn draw_secret<DB>(
+    chart: &mut ChartContext<DB, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+    shares: &[Share],
+    label: &str,
+    color: RGBColor,
+    mode: Mode,
+) -> Result<(), Error>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let secret = (0.0f32, combine(shares)? as f32);
+    draw_points(chart, vec![secret], color.filled(), mode)?
+        .label(label)
+        .legend(move |(x, y)| Circle::new((x, y), 5, color.filled()));
+
+    Ok(())
+}
+
+/// Draws a corrupted share on a chart.
+/// The corrupted share is drawn as a point, joined by a line to
+/// the position of the honest share it replaces.
+/// The chart is updated in place.
+/// The corrupted share is labeled in the legend, drawn in magenta with a size of 5.
+pub fn draw_corrupted_share<DB>(
+    chart: &mut ChartContext<DB, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+    polynomial: &Polynomial,
+    share: &Share,
+    mode: Mode,
+) -> Result<(), Error>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let share = point(share);
+    let (x, _) = share;
+    chart.draw_series(LineSeries::new(
+        vec![(x, evaluate(polynomial, x)), share],
+        MAGENTA.mix(0.5).stroke_width(mode.stroke_width()),
+    ))?;
+    draw_points(chart, vec![share], MAGENTA.filled(), mode)?
+        .label("Corrupted share")
+        .legend(|(x, y)| Circle::new((x, y), 5, MAGENTA.filled()));
+
+    Ok(())
+}
+
+/// Draws points on a chart.
+/// The points are drawn as circles with a size of 5 in the given style,
+/// annotated with their coordinates unless the mode is compact.
+/// The chart is updated in place.
+/// Returns the series annotation, so that the caller can label the points in the legend.
+pub fn draw_points<'a, 'b, DB>(
+    chart: &'b mut ChartContext<'a, DB, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+    points: Vec<(f32, f32)>,
+    style: ShapeStyle,
+    mode: Mode,
+) -> Result<&'b mut SeriesAnno<'a, DB>, Error>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    register_font();
+    let annotation = match mode {
+        Mode::Full | Mode::Annotated => chart.draw_series(PointSeries::of_element(
+            points,
+            5,
+            style,
+            &|coord, size, style| {
+                EmptyElement::at(coord)
+                    + Circle::new((0, 0), size, style)
+                    + Text::new(format!("{:.1?}", coord), (1, 10), ("sans-serif", 15))
+            },
+        ))?,
+        Mode::Compact => chart.draw_series(PointSeries::of_element(
+            points,
+            5,
+            style,
+            &|coord, size, style| EmptyElement::at(coord) + Circle::new((0, 0), size, style),
+        ))?,
+    };
+    Ok(annotation)
+}
+
+/// Returns the shares of the polynomial at the given x-coordinates,
+/// any `k` of which reconstruct the secret of a polynomial of degree `k - 1`.
+pub fn shares_of(polynomial: &Polynomial, shares_x: &[f32]) -> Vec<Share> {
+    let threshold = polynomial.coefficients().len();
+    shares_x
+        .iter()
+        .map(|&x| {
+            let x = f64::from(x);
+            Share::new(x, polynomial.evaluate(x), threshold, shares_x.len())
+        })
+        .collect()
+}
+
+/// Returns the polynomial interpolating the shares.
+pub fn interpolate(shares: &[Share]) -> Result<Polynomial, Error> {
+    let points: Vec<(f64, f64)> = shares.iter().map(|share| (share.x(), share.y())).collect();
+    Polynomial::interpolate(&points)
+}
+
+/// Evaluates a polynomial at a coordinate of the chart.
+pub fn evaluate(polynomial: &Polynomial, x: f32) -> f32 {
+    polynomial.evaluate(x.into()) as f32
+}
+
+/// Returns the coordinates of a share on the chart.
+pub fn point(share: &Share) -> (f32, f32) {
+    (share.x() as f32, share.y() as f32)
+}
+
+/// Makes the colors of an SVG chart themable.
+/// A style sheet is inserted after the opening tag, replacing every color
+/// with a CSS custom property that falls back to the color itself,
+/// so that documentation can restyle the charts for light and dark themes.
+pub fn apply_theme(svg: &str) -> String {
+    let shares =
+        (1..=THEMED_SHARES).map(|index| (share_color(index).color, format!("--sss-share-{index}")));
+    let rules: String = THEME
+        .iter()
+        .map(|&(color, property)| (color.to_rgba(), property.to_string()))
+        .chain(shares)
+        .flat_map(|(color, property)| {
+            let (r, g, b) = color.rgb();
+            let color = format!("#{r:02X}{g:02X}{b:02X}");
+            ["fill", "stroke"].map(|attribute| {
+                format!("[{attribute}=\"{color}\"]{{{attribute}:var({property},{color})}}")
+            })
+        })
+        .collect();
+
+    match svg.find('>') {
+        Some(end) => {
+            let (opening, rest) = svg.split_at(end + 1);
+            // renderers without custom properties skip the whole block,
+            // and keep drawing the colors of the attributes
+            let style = format!("<style>@supports (color: var(--sss)) {{{rules}}}</style>");
+            format!("{opening}\n{style}{rest}")
+        }
+        None => svg.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the hue of a color, in degrees.
+    fn hue(color: RGBAColor) -> f64 {
+        let [r, g, b] = [color.0, color.1, color.2].map(|c| f64::from(c) / 255.0);
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        let hue = if max == r {
+            (g - b) / (max - min)
+        } else if max == g {
+            (b - r) / (max - min) + 2.0
+        } else {
+            (r - g) / (max - min) + 4.0
+        };
+        (hue * 60.0).rem_euclid(360.0)
+    }
+
+    /// The chart of a line of the image generator, written to `plots/line.svg`.
+    fn line() -> ChartSpec {
+        ChartSpec {
+            title: "2 Points are Uniquely Determined by a Line".to_string(),
+            dimensions: Mode::Full.dimensions(),
+            x_range: 2.5..4.5,
+            y_range: 2.0..4.5,
+            polynomial: Polynomial::new(vec![0.0, 1.0]),
+            polynomial_str: "x".to_string(),
+            shares_x: vec![3.0, 4.0],
+            secret: false,
+            inset: None,
+            mode: Mode::Full,
+        }
+    }
+
+    #[test]
+    fn renders_the_same_bytes_with_the_bundled_font() {
+        // the text is measured with the bundled font, whatever the installed fonts
+        assert_eq!(
+            render_to_string(&line()).unwrap(),
+            include_str!("../plots/line.svg")
+        );
+
+        let png = render_to_png_bytes(&line()).unwrap();
+        assert_eq!(png, render_to_png_bytes(&line()).unwrap());
+        let image = image::load_from_memory_with_format(&png, ImageFormat::Png)
+            .unwrap()
+            .to_rgb8();
+        // the caption is rasterized in black over the white background
+        let caption = image.rows().skip(5).take(20).flatten();
+        assert!(caption.into_iter().any(|pixel| pixel.0 == [0, 0, 0]));
+    }
+
+    #[test]
+    fn interpolates_the_shares_of_a_polynomial() {
+        // 1 + x²
+        let polynomial = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        let shares = shares_of(&polynomial, &[1.0, 2.0, 3.0]);
+        assert_eq!(
+            shares.iter().map(point).collect::<Vec<_>>(),
+            [(1.0, 2.0), (2.0, 5.0), (3.0, 10.0)]
+        );
+        assert_eq!(evaluate(&interpolate(&shares).unwrap(), 4.0), 17.0);
+    }
+
+    #[test]
+    fn themes_the_colors_of_svg_charts() {
+        let svg = apply_theme(r##"<svg width="1"><rect fill="#0000FF"/></svg>"##);
+        assert!(svg.starts_with("<svg width=\"1\">\n<style>"));
+        assert!(svg.contains(r##"[fill="#0000FF"]{fill:var(--sss-polynomial,#0000FF)}"##));
+        assert!(svg.ends_with(r##"<rect fill="#0000FF"/></svg>"##));
+    }
+
+    #[test]
+    fn share_colors_stay_clear_of_the_other_hues() {
+        for index in 1..=100 {
+            let hue = hue(share_color(index).color);
+            for reserved in [120.0, 240.0, 300.0] {
+                let distance = (hue - reserved).abs();
+                assert!(
+                    distance.min(360.0 - distance) >= 29.0,
+                    "share {index}: {hue}°"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn memory_sink_keeps_every_chart() {
+        let mut sink = MemorySink::new();
+        sink.write(Path::new("line.svg"), b"<svg/>").unwrap();
+        sink.write(Path::new("line.svg"), b"<svg></svg>").unwrap();
+        assert_eq!(sink.get(Path::new("line.svg")), Some(&b"<svg></svg>"[..]));
+        assert_eq!(sink.get(Path::new("cubic.svg")), None);
+
+        let mut sink = WriterSink(Vec::new());
+        sink.write(Path::new("line.svg"), b"<svg/>").unwrap();
+        assert_eq!(sink.0, b"<svg/>");
+    }
+}
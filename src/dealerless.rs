@@ -0,0 +1,247 @@
+//! Dealer-less generation of a shared secret.
+//!
+//! Instead of a dealer splitting a secret it knows,
+//! every participant contributes a random secret of its own,
+//! split into one share for every participant.
+//! Each participant then aggregates the shares it received
+//! into its share of the sum of all contributions.
+//!
+//! Since shares are additive in any field, the aggregated shares are the shares
+//! of the sum of the contributions, a secret that no single participant (or machine) ever held.
+//! Over GF(2⁸), the sum of bytes is a byte, hence they reconstruct it with [`crate::combine`].
+//!
+//! With the `vss` feature, the contributions are shared with Feldman's scheme,
+//! see the `feldman` module, and published along with their commitments:
+//! every participant verifies the shares it received,
+//! and the sum of the commitments is the commitment to the shared secret.
+
+#[cfg(feature = "vss")]
+use curve25519_dalek::RistrettoPoint;
+use rand::{CryptoRng, Rng, RngCore};
+
+use crate::error::Error;
+#[cfg(feature = "vss")]
+use crate::feldman::{self, Commitments};
+use crate::field::Field;
+#[cfg(feature = "vss")]
+use crate::ristretto::RistrettoScalar;
+use crate::{split_with_rng, Share};
+
+/// Generates the contribution of one participant to a shared secret.
+///
+/// The contribution is a random secret of `length` bytes,
+/// split into `n` shares, any `k` of which reconstruct it.
+/// The share at x = i must be sent to the i-th participant,
+/// and the contribution itself is never returned.
+///
+/// ## Errors
+///
+/// Returns an error if `length` is zero,
+/// or if the threshold or the number of shares are invalid, see [`crate::split`].
+pub fn contribute(length: usize, k: usize, n: usize) -> Result<Vec<Share>, Error> {
+    contribute_with_rng(length, k, n, &mut rand::thread_rng())
+}
+
+/// Generates the contribution of one participant to a shared secret,
+/// drawing the contribution and its polynomials from the given random number generator.
+///
+/// See [`contribute`] for the details and errors.
+pub fn contribute_with_rng<R: CryptoRng + RngCore + ?Sized>(
+    length: usize,
+    k: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, Error> {
+    let contribution: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+    split_with_rng(&contribution, k, n, rng)
+}
+
+/// Generates the verifiable contribution of one participant to a shared secret,
+/// a random scalar of the Ristretto scalar field,
+/// split into `n` shares, any `k` of which reconstruct it,
+/// along with the commitments to its polynomial, to publish.
+///
+/// ## Errors
+///
+/// Returns an error if `k` is zero or greater than `n`.
+#[cfg(feature = "vss")]
+pub fn contribute_verifiable(
+    k: usize,
+    n: usize,
+) -> Result<(Vec<Share<RistrettoScalar>>, Commitments), Error> {
+    contribute_verifiable_with_rng(k, n, &mut rand::thread_rng())
+}
+
+/// Generates the verifiable contribution of one participant to a shared secret,
+/// drawing the contribution and its polynomial from the given random number generator.
+///
+/// See [`contribute_verifiable`] for the details and errors.
+#[cfg(feature = "vss")]
+pub fn contribute_verifiable_with_rng<R: CryptoRng + RngCore + ?Sized>(
+    k: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<(Vec<Share<RistrettoScalar>>, Commitments), Error> {
+    feldman::split_with_rng(&RistrettoScalar::random(rng), k, n, rng)
+}
+
+/// Aggregates the commitments of every contribution
+/// into the commitments to the polynomial of the shared secret,
+/// against which the aggregated shares verify, see [`feldman::verify`].
+///
+/// ## Errors
+///
+/// Returns an error if there are no commitments,
+/// or if the contributions were split with different thresholds,
+/// hence have commitments of different lengths.
+#[cfg(feature = "vss")]
+pub fn aggregate_commitments(commitments: &[Commitments]) -> Result<Commitments, Error> {
+    let Some(first) = commitments.first() else {
+        return Err(Error::NoShares);
+    };
+    if commitments
+        .iter()
+        .any(|commitments| commitments.points().len() != first.points().len())
+    {
+        return Err(Error::LengthMismatch);
+    }
+
+    Ok(Commitments::from_points(
+        (0..first.points().len())
+            .map(|j| {
+                commitments
+                    .iter()
+                    .map(|commitments| commitments.points()[j])
+                    .sum::<RistrettoPoint>()
+            })
+            .collect(),
+    ))
+}
+
+/// Aggregates the shares received by one participant,
+/// one from every contribution, into its share of the shared secret.
+///
+/// ## Errors
+///
+/// Returns an error if there are no shares,
+/// if the shares have different x-coordinates,
+/// if they were split with different thresholds or numbers of shares,
+/// or if the shares have different lengths.
+pub fn aggregate<F: Field>(shares: &[Share<F>]) -> Result<Share<F>, Error> {
+    let Some(first) = shares.first() else {
+        return Err(Error::NoShares);
+    };
+    if shares.iter().any(|share| share.x() != first.x()) {
+        return Err(Error::XCoordinateMismatch);
+    }
+    if let Some(share) = shares
+        .iter()
+        .find(|share| share.threshold() != first.threshold())
+    {
+        return Err(Error::ThresholdMismatch {
+            expected: first.threshold(),
+            found: share.threshold(),
+        });
+    }
+    if let Some(share) = shares.iter().find(|share| share.total() != first.total()) {
+        return Err(Error::MixedShareSets {
+            expected: first.total(),
+            found: share.total(),
+        });
+    }
+    if shares
+        .iter()
+        .any(|share| share.y().len() != first.y().len())
+    {
+        return Err(Error::LengthMismatch);
+    }
+
+    let y = (0..first.y().len())
+        .map(|i| {
+            shares
+                .iter()
+                .fold(F::zero(), |sum, share| sum + share.y()[i].clone())
+        })
+        .collect();
+
+    Ok(Share::new(
+        first.x().clone(),
+        y,
+        first.threshold(),
+        first.total(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combine;
+
+    /// Aggregates the shares of every contribution received by the i-th participant.
+    fn received<F: Field>(contributions: &[Vec<Share<F>>], i: usize) -> Vec<Share<F>> {
+        contributions
+            .iter()
+            .map(|shares| shares[i].clone())
+            .collect()
+    }
+
+    #[test]
+    fn aggregated_shares_reconstruct_the_shared_secret() {
+        let contributions: Vec<Vec<Share>> =
+            (0..3).map(|_| contribute(16, 2, 3).unwrap()).collect();
+        let shares: Vec<Share> = (0..3)
+            .map(|i| aggregate(&received(&contributions, i)).unwrap())
+            .collect();
+        let secret = combine(&shares[..2]).unwrap();
+        assert_eq!(secret.len(), 16);
+        assert_eq!(combine(&shares[1..]).unwrap(), secret);
+    }
+
+    #[test]
+    fn rejects_mixed_contributions() {
+        let contributions = [contribute(16, 2, 3).unwrap(), contribute(16, 3, 3).unwrap()];
+        assert!(matches!(
+            aggregate(&received(&contributions, 0)),
+            Err(Error::ThresholdMismatch {
+                expected: 2,
+                found: 3
+            })
+        ));
+        let contributions = [contribute(16, 2, 3).unwrap(), contribute(8, 2, 3).unwrap()];
+        assert!(matches!(
+            aggregate(&received(&contributions, 0)),
+            Err(Error::LengthMismatch)
+        ));
+        let shares = contribute(16, 2, 3).unwrap();
+        assert!(matches!(
+            aggregate(&shares[..2]),
+            Err(Error::XCoordinateMismatch)
+        ));
+    }
+
+    #[cfg(feature = "vss")]
+    #[test]
+    fn aggregated_commitments_verify_the_aggregated_shares() {
+        let (contributions, commitments): (Vec<_>, Vec<_>) =
+            (0..3).map(|_| contribute_verifiable(2, 3).unwrap()).unzip();
+        let commitments = aggregate_commitments(&commitments).unwrap();
+        for i in 0..3 {
+            let share = aggregate(&received(&contributions, i)).unwrap();
+            assert!(feldman::verify(&share, &commitments));
+        }
+    }
+
+    #[cfg(feature = "vss")]
+    #[test]
+    fn rejects_commitments_of_different_lengths() {
+        let commitments = [
+            contribute_verifiable(2, 3).unwrap().1,
+            contribute_verifiable(3, 3).unwrap().1,
+        ];
+        assert!(matches!(
+            aggregate_commitments(&commitments),
+            Err(Error::LengthMismatch)
+        ));
+        assert!(matches!(aggregate_commitments(&[]), Err(Error::NoShares)));
+    }
+}
@@ -0,0 +1,63 @@
+//! Deterministic splitting from a seed.
+//!
+//! The coefficients of the polynomials are drawn from ChaCha20
+//! keyed with a user-supplied seed, instead of fresh randomness,
+//! so that splitting the same secret with the same seed
+//! regenerates exactly the same shares, for audits and recovery drills.
+//!
+//! The seed is as sensitive as the secret: anyone holding it can
+//! recompute the polynomials from fewer than `k` shares.
+//! A seed must also never be reused for another secret,
+//! as the shares of both would then reveal the difference of the secrets.
+//!
+//! This module requires the `deterministic` feature.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+use crate::error::Error;
+use crate::field::Field;
+use crate::gf256::Gf256;
+use crate::{split_over_with_rng, Share};
+
+/// The seed of the deterministic random number generator.
+pub type Seed = [u8; 32];
+
+/// Splits a secret into `n` shares over GF(2⁸), any `k` of which reconstruct it,
+/// deriving the polynomials from the seed.
+///
+/// See [`split_over`] for the details and errors.
+pub fn split(secret: &[u8], k: usize, n: usize, seed: Seed) -> Result<Vec<Share>, Error> {
+    split_over::<Gf256>(secret, k, n, seed)
+}
+
+/// Splits a secret into `n` shares over the field `F`, any `k` of which reconstruct it,
+/// deriving the polynomials from the seed.
+///
+/// The same secret, parameters and seed always yield the same shares.
+///
+/// ## Errors
+///
+/// See [`crate::split_over`].
+pub fn split_over<F: Field>(
+    secret: &[u8],
+    k: usize,
+    n: usize,
+    seed: Seed,
+) -> Result<Vec<Share<F>>, Error> {
+    split_over_with_rng(secret, k, n, &mut ChaCha20Rng::from_seed(seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combine;
+
+    #[test]
+    fn the_same_seed_yields_the_same_shares() {
+        let shares = split(b"secret", 3, 5, [7; 32]).unwrap();
+        assert_eq!(split(b"secret", 3, 5, [7; 32]).unwrap(), shares);
+        assert_ne!(split(b"secret", 3, 5, [8; 32]).unwrap(), shares);
+        assert_eq!(combine(&shares[1..4]).unwrap(), b"secret");
+    }
+}
@@ -0,0 +1,181 @@
+//! Errors of the crate.
+//!
+//! Every fallible function returns an [`Error`],
+//! whose variants tell apart the causes of a failure.
+
+use plotters::drawing::DrawingAreaErrorKind;
+
+/// An error while splitting, combining or plotting shares.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The threshold is zero.
+    #[error("the threshold must be at least 1")]
+    ZeroThreshold,
+
+    /// The threshold is greater than the number of shares.
+    #[error("threshold {threshold} exceeds the {shares} shares, the secret could never be reconstructed")]
+    ThresholdAboveShares {
+        /// The number of shares needed to reconstruct the secret.
+        threshold: usize,
+        /// The number of shares to generate.
+        shares: usize,
+    },
+
+    /// There are more shares than non-zero elements of the field.
+    #[error("cannot generate {shares} shares, the field supports at most {max}")]
+    TooManyShares {
+        /// The number of shares to generate.
+        shares: usize,
+        /// The maximum number of shares of the field.
+        max: u64,
+    },
+
+    /// A parameter of a split is not set.
+    #[error("the {0} is not set")]
+    MissingParameter(&'static str),
+
+    /// The shares are labeled, but there is not one label for every share.
+    #[error("{labels} labels for {shares} shares")]
+    LabelMismatch {
+        /// The number of labels.
+        labels: usize,
+        /// The number of shares to generate.
+        shares: usize,
+    },
+
+    /// The secret is empty.
+    #[error("the secret is empty")]
+    EmptySecret,
+
+    /// The block size of the padding is zero.
+    #[error("the block size of the padding must be at least 1")]
+    ZeroBlockSize,
+
+    /// The field has too few elements to hold every byte of a secret.
+    #[error("the field cannot hold every byte, bytes would be reconstructed as other bytes")]
+    FieldTooSmall,
+
+    /// The secret does not fit in an element of the field.
+    #[error("secret must be smaller than {bound}")]
+    SecretTooLarge {
+        /// The exclusive upper bound of the secret, the order of the field.
+        bound: &'static str,
+    },
+
+    /// A share is, or would be, evaluated at x = 0,
+    /// where its y-coordinates would be taken as the secret.
+    #[error("shares cannot have the x-coordinate 0, which is the secret")]
+    ZeroXCoordinate,
+
+    /// There are no shares.
+    #[error("no shares")]
+    NoShares,
+
+    /// The shares hold no y-coordinate, hence no secret.
+    #[error("shares hold no y-coordinate")]
+    EmptyShares,
+
+    /// Two shares have the same x-coordinate.
+    #[error("duplicate share x-coordinates")]
+    DuplicateShares,
+
+    /// Fewer shares than their threshold are combined,
+    /// which would reconstruct an unrelated secret.
+    #[error("{shares} shares are fewer than the threshold {threshold}, the secret cannot be reconstructed")]
+    InsufficientShares {
+        /// The threshold of the shares.
+        threshold: usize,
+        /// The number of shares.
+        shares: usize,
+    },
+
+    /// The shares were split with different thresholds.
+    #[error("shares have different thresholds {expected} and {found}")]
+    ThresholdMismatch {
+        /// The threshold of the first share.
+        expected: usize,
+        /// The threshold of another share.
+        found: usize,
+    },
+
+    /// The shares were split into different numbers of shares,
+    /// hence they come from different share sets.
+    #[error("shares come from splits of {expected} and {found} shares, share sets are mixed")]
+    MixedShareSets {
+        /// The number of shares of the split of the first share.
+        expected: usize,
+        /// The number of shares of the split of another share.
+        found: usize,
+    },
+
+    /// The shares carry different fingerprints of the secret,
+    /// hence they come from different share sets.
+    #[error("shares have different fingerprints, share sets are mixed")]
+    FingerprintMismatch,
+
+    /// Some shares are padded and others are not,
+    /// hence they come from different share sets.
+    #[error("shares are not all padded, share sets are mixed")]
+    PaddingMismatch,
+
+    /// Shares expected at the same x-coordinate have different x-coordinates.
+    #[error("shares have different x-coordinates")]
+    XCoordinateMismatch,
+
+    /// The shares, or commitments, have different lengths, or not the expected length.
+    #[error("shares have different lengths")]
+    LengthMismatch,
+
+    /// Some shares do not lie on the polynomial of the majority of the shares,
+    /// identified by their indices among the shares.
+    #[error("shares {0:?} are inconsistent with the other shares, they are incorrect")]
+    InconsistentShares(Vec<usize>),
+
+    /// Too many points are incorrect to decode their polynomial.
+    #[error("too many points are incorrect to decode their polynomial")]
+    Undecodable,
+
+    /// Some shares do not lie on the polynomial of the others,
+    /// but too few shares are correct to identify the incorrect ones,
+    /// for example with only one share more than the threshold.
+    #[error(
+        "shares are inconsistent, but too few of them are correct to identify the incorrect ones"
+    )]
+    UnidentifiableShares,
+
+    /// A secret key is zero, hence not invertible to decrypt shares.
+    #[error("the secret key is zero")]
+    ZeroSecretKey,
+
+    /// A share is decrypted with the key pair of another participant.
+    #[error("share is encrypted to another public key")]
+    KeyMismatch,
+
+    /// A reconstructed value cannot be decoded, for example into a byte.
+    #[error("reconstructed value {0} is not a byte")]
+    Decode(String),
+
+    /// The reconstructed secret of padded shares does not end with a valid padding.
+    #[error(
+        "reconstructed secret has an invalid padding, the shares are insufficient or incorrect"
+    )]
+    InvalidPadding,
+
+    /// The reconstructed secret does not match the fingerprint of the shares.
+    #[error("reconstructed secret does not match its fingerprint, the shares are insufficient or incorrect")]
+    IncorrectSecret,
+
+    /// A chart could not be drawn.
+    #[error("plotting failed: {0}")]
+    Plotting(Box<dyn std::error::Error + Send + Sync>),
+
+    /// A file could not be read or written.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl<E: std::error::Error + Send + Sync + 'static> From<DrawingAreaErrorKind<E>> for Error {
+    fn from(error: DrawingAreaErrorKind<E>) -> Self {
+        Error::Plotting(Box::new(error))
+    }
+}
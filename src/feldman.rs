@@ -0,0 +1,191 @@
+//! Feldman's verifiable secret sharing.
+//!
+//! A dishonest dealer could hand out shares that lie on no common polynomial,
+//! and the participants would only find out when reconstructing the secret.
+//! Instead, the dealer publishes a commitment Cⱼ = aⱼG to every coefficient aⱼ
+//! of the polynomial, where G is the generator of the Ristretto group.
+//! The holder of the share (x, y) verifies it without learning the secret,
+//! by checking that yG is the sum of xʲCⱼ, the commitment to the polynomial evaluated at x.
+//!
+//! The commitment C₀ = sG to the secret s is public,
+//! hence the secret is only computationally hidden,
+//! and must have enough entropy not to be guessed, such as a random key.
+//!
+//! This module requires the `vss` feature.
+
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::RistrettoPoint;
+use rand::{CryptoRng, RngCore};
+
+use crate::error::Error;
+use crate::field::Field;
+use crate::polynomial::Polynomial;
+use crate::ristretto::RistrettoScalar;
+use crate::validation::validate_parameters;
+use crate::{interpolate_at, Share};
+
+/// The commitments to the coefficients of the polynomial of a split,
+/// from the constant term up.
+///
+/// The commitment to the polynomial evaluated at x is the sum of xʲCⱼ,
+/// whichever way the coefficients were committed to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Commitments(Vec<RistrettoPoint>);
+
+impl Commitments {
+    /// Commits to the coefficients of a polynomial.
+    pub fn new(polynomial: &Polynomial<RistrettoScalar>) -> Self {
+        Commitments(
+            polynomial
+                .coefficients()
+                .iter()
+                .map(|coefficient| RISTRETTO_BASEPOINT_POINT * coefficient.0)
+                .collect(),
+        )
+    }
+
+    /// Wraps the commitments to the coefficients, from the constant term up,
+    /// such as the ones published by the dealer and received by a participant,
+    /// or the blinded commitments of [`crate::pedersen`].
+    pub fn from_points(points: Vec<RistrettoPoint>) -> Self {
+        Commitments(points)
+    }
+
+    /// Returns the commitments to the coefficients, from the constant term up.
+    pub fn points(&self) -> &[RistrettoPoint] {
+        &self.0
+    }
+
+    /// Returns the commitment to the polynomial evaluated at `x`,
+    /// with Horner's method, as in [`Polynomial::evaluate`].
+    pub fn evaluate(&self, x: &RistrettoScalar) -> RistrettoPoint {
+        self.0
+            .iter()
+            .rev()
+            .fold(RistrettoPoint::default(), |point, commitment| {
+                point * x.0 + commitment
+            })
+    }
+}
+
+/// Splits a secret into `n` shares, any `k` of which reconstruct it,
+/// along with the commitments to the polynomial.
+///
+/// The secret is the constant term of a single polynomial over the Ristretto scalar field,
+/// hence every share holds a single y-coordinate.
+///
+/// ## Errors
+///
+/// Returns an error if `k` is zero or greater than `n`.
+pub fn split(
+    secret: &RistrettoScalar,
+    k: usize,
+    n: usize,
+) -> Result<(Vec<Share<RistrettoScalar>>, Commitments), Error> {
+    split_with_rng(secret, k, n, &mut rand::thread_rng())
+}
+
+/// Splits a secret into `n` shares, any `k` of which reconstruct it,
+/// along with the commitments to the polynomial,
+/// drawing the coefficients of the polynomial from the given random number generator.
+///
+/// See [`split`] for the details and errors.
+pub fn split_with_rng<R: CryptoRng + RngCore + ?Sized>(
+    secret: &RistrettoScalar,
+    k: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<(Vec<Share<RistrettoScalar>>, Commitments), Error> {
+    validate_parameters::<RistrettoScalar>(k, n)?;
+
+    let polynomial = Polynomial::random(*secret, k - 1, rng);
+    let shares = (1..=n as u64)
+        .map(|i| {
+            let x = RistrettoScalar::from_u64(i);
+            Share::new(x, vec![polynomial.evaluate(&x)], k, n)
+        })
+        .collect();
+
+    Ok((shares, Commitments::new(&polynomial)))
+}
+
+/// Verifies a share against the commitments of its split.
+///
+/// Returns whether the share lies on the committed polynomial,
+/// which is false for a share without exactly one y-coordinate,
+/// or if the polynomial has more coefficients than the threshold of the share,
+/// since any `k` shares of such a polynomial would reconstruct different secrets.
+pub fn verify(share: &Share<RistrettoScalar>, commitments: &Commitments) -> bool {
+    if commitments.points().len() > share.threshold() {
+        return false;
+    }
+    match share.y() {
+        [y] => RISTRETTO_BASEPOINT_POINT * y.0 == commitments.evaluate(share.x()),
+        _ => false,
+    }
+}
+
+/// Combines shares to reconstruct the secret.
+///
+/// ## Errors
+///
+/// Returns an error if the shares do not hold exactly one y-coordinate,
+/// or if they are inconsistent, see [`crate::interpolate_at`].
+pub fn combine(shares: &[Share<RistrettoScalar>]) -> Result<RistrettoScalar, Error> {
+    if shares.iter().any(|share| share.y().len() != 1) {
+        return Err(Error::LengthMismatch);
+    }
+    let secret = interpolate_at(shares, &RistrettoScalar::zero())?;
+    Ok(secret[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_verifies_every_share() {
+        let secret = RistrettoScalar::from_u64(42);
+        let (shares, commitments) = split(&secret, 3, 5).unwrap();
+        assert!(shares.iter().all(|share| verify(share, &commitments)));
+        assert_eq!(combine(&shares[2..]).unwrap(), secret);
+    }
+
+    #[test]
+    fn rejects_tampered_shares() {
+        let (shares, commitments) = split(&RistrettoScalar::from_u64(42), 3, 5).unwrap();
+        let y = shares[0].y()[0] + RistrettoScalar::one();
+        let tampered = Share::new(*shares[0].x(), vec![y], 3, 5);
+        assert!(!verify(&tampered, &commitments));
+        let moved = Share::new(*shares[1].x(), shares[0].y().to_vec(), 3, 5);
+        assert!(!verify(&moved, &commitments));
+    }
+
+    #[test]
+    fn verifies_against_the_published_points() {
+        let (shares, commitments) = split(&RistrettoScalar::from_u64(42), 3, 5).unwrap();
+        let published = Commitments::from_points(commitments.points().to_vec());
+        assert_eq!(published, commitments);
+        assert!(shares.iter().all(|share| verify(share, &published)));
+    }
+
+    #[test]
+    fn rejects_a_forged_share_at_zero() {
+        let (shares, _) = split(&RistrettoScalar::from_u64(42), 3, 5).unwrap();
+        let forged = Share::new(RistrettoScalar::zero(), vec![RistrettoScalar::one()], 3, 5);
+        assert!(matches!(
+            combine(&[forged, shares[1].clone(), shares[2].clone()]),
+            Err(Error::ZeroXCoordinate)
+        ));
+    }
+
+    #[test]
+    fn rejects_commitments_above_the_threshold_degree() {
+        let (shares, commitments) = split(&RistrettoScalar::from_u64(42), 4, 5).unwrap();
+        let relabeled: Vec<_> = shares
+            .iter()
+            .map(|share| Share::new(*share.x(), share.y().to_vec(), 3, 5))
+            .collect();
+        assert!(relabeled.iter().all(|share| !verify(share, &commitments)));
+    }
+}
@@ -0,0 +1,70 @@
+//! Finite fields to share secrets over.
+//!
+//! The [`Field`] trait abstracts the arithmetic needed by
+//! [`crate::split_over`] and [`crate::combine`],
+//! so that secrets can be shared over GF(2⁸), see [`crate::gf256`],
+//! over GF(2¹⁶), see [`crate::gf65536`],
+//! or over a prime field GF(p), see [`crate::prime`],
+//! such as the scalar field of the Ristretto group with the `vss` feature.
+//! Floating-point numbers are not a finite field and do not implement it,
+//! the charts use the polynomials of [`crate::real`] instead.
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use rand::Rng;
+
+/// A finite field.
+///
+/// Secret bytes and share indices are mapped into the field with [`Field::from_u64`],
+/// and reconstructed bytes are mapped back with [`Field::to_u64`].
+pub trait Field:
+    Clone
+    + Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The maximum number of shares, that is the number of non-zero elements,
+    /// as x = 0 is reserved for the secret.
+    const MAX_SHARES: u64;
+
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+
+    /// Returns the multiplicative inverse of the element,
+    /// or `None` for zero, which has no inverse.
+    fn inverse(&self) -> Option<Self>;
+
+    /// Draws an element uniformly at random.
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self;
+
+    /// Maps an integer into the field.
+    ///
+    /// Integers up to [`Field::MAX_SHARES`] are mapped to distinct elements.
+    fn from_u64(value: u64) -> Self;
+
+    /// Maps an element back to the integer it was mapped from,
+    /// or `None` if it does not fit in a `u64`.
+    fn to_u64(&self) -> Option<u64>;
+
+    /// Raises the element to the given power by square-and-multiply.
+    fn pow(&self, mut exponent: u64) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base.clone();
+            }
+            base = base.clone() * base;
+            exponent >>= 1;
+        }
+        result
+    }
+}
@@ -0,0 +1,74 @@
+//! Fingerprints of secrets, to verify their reconstruction.
+//!
+//! Combining the wrong shares or shares of another split with the same parameters
+//! silently yields an unrelated secret.
+//! A [`Fingerprint`] embedded in every share, the SHA-256 hash of a random salt
+//! followed by the secret, lets [`crate::combine`] confirm that the reconstructed
+//! secret is the one that was split, and report an error otherwise.
+//!
+//! The salt is stored in the shares, hence it does not slow down guessing:
+//! anyone holding a single share can check guesses of the secret against the fingerprint.
+//! Only fingerprint secrets with enough entropy, such as random keys, never passwords.
+
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+/// The number of bytes of the salt of a fingerprint.
+pub const SALT_LENGTH: usize = 16;
+
+/// A salted SHA-256 hash of a secret.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint {
+    salt: [u8; SALT_LENGTH],
+    digest: [u8; 32],
+}
+
+impl Fingerprint {
+    /// Computes the fingerprint of a secret, with a salt drawn from the given random number generator.
+    pub fn new<R: CryptoRng + RngCore + ?Sized>(secret: &[u8], rng: &mut R) -> Self {
+        let mut salt = [0; SALT_LENGTH];
+        rng.fill_bytes(&mut salt);
+        Fingerprint::with_salt(salt, secret)
+    }
+
+    /// Computes the fingerprint of a secret with the given salt.
+    pub fn with_salt(salt: [u8; SALT_LENGTH], secret: &[u8]) -> Self {
+        let digest = Sha256::new()
+            .chain_update(salt)
+            .chain_update(secret)
+            .finalize()
+            .into();
+        Fingerprint { salt, digest }
+    }
+
+    /// Returns the salt of the fingerprint.
+    pub fn salt(&self) -> &[u8; SALT_LENGTH] {
+        &self.salt
+    }
+
+    /// Returns the SHA-256 hash of the salt followed by the secret.
+    pub fn digest(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Checks that the fingerprint is the one of the secret.
+    pub fn verify(&self, secret: &[u8]) -> bool {
+        Fingerprint::with_salt(self.salt, secret) == *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_only_its_secret() {
+        let fingerprint = Fingerprint::new(b"secret", &mut rand::thread_rng());
+        assert!(fingerprint.verify(b"secret"));
+        assert!(!fingerprint.verify(b"secreu"));
+        assert_eq!(
+            Fingerprint::with_salt(*fingerprint.salt(), b"secret"),
+            fingerprint
+        );
+    }
+}
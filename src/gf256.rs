@@ -0,0 +1,238 @@
+//! Arithmetic over the finite field GF(2⁸).
+//!
+//! Elements are bytes, seen as polynomials over GF(2) of degree less than 8,
+//! reduced modulo the AES polynomial x⁸ + x⁴ + x³ + x + 1.
+//! Addition and subtraction are both XOR,
+//! and every non-zero element has a multiplicative inverse.
+
+// Field addition is XOR and division multiplies by the inverse.
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use rand::Rng;
+
+use crate::field::Field;
+
+/// The low byte of the AES polynomial x⁸ + x⁴ + x³ + x + 1.
+const AES_POLYNOMIAL: u8 = 0x1b;
+
+/// An element of GF(2⁸).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Gf256(pub u8);
+
+impl Gf256 {
+    /// The additive identity.
+    pub const ZERO: Gf256 = Gf256(0);
+
+    /// The multiplicative identity.
+    pub const ONE: Gf256 = Gf256(1);
+
+    /// Raises the element to the given power by square-and-multiply.
+    pub fn pow(self, mut exponent: u32) -> Gf256 {
+        let mut base = self;
+        let mut result = Gf256::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse of the element,
+    /// or `None` for zero, which has no inverse.
+    ///
+    /// The multiplicative group has order 255, hence the inverse is a²⁵⁴.
+    pub fn inverse(self) -> Option<Gf256> {
+        if self == Gf256::ZERO {
+            None
+        } else {
+            Some(self.pow(254))
+        }
+    }
+}
+
+impl From<u8> for Gf256 {
+    fn from(value: u8) -> Self {
+        Gf256(value)
+    }
+}
+
+impl From<Gf256> for u8 {
+    fn from(value: Gf256) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Gf256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04x}", self.0)
+    }
+}
+
+impl Add for Gf256 {
+    type Output = Gf256;
+
+    fn add(self, rhs: Gf256) -> Gf256 {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+impl Sub for Gf256 {
+    type Output = Gf256;
+
+    fn sub(self, rhs: Gf256) -> Gf256 {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+impl Neg for Gf256 {
+    type Output = Gf256;
+
+    fn neg(self) -> Gf256 {
+        self
+    }
+}
+
+impl Mul for Gf256 {
+    type Output = Gf256;
+
+    /// Multiplies two elements by shift-and-add over the 8 bits of `rhs`,
+    /// reducing by the AES polynomial whenever the degree reaches 8.
+    /// The additions and reductions are masked rather than branched on,
+    /// so that the time taken does not depend on the elements, such as the bytes of a secret.
+    fn mul(self, rhs: Gf256) -> Gf256 {
+        let (mut a, mut b) = (self.0, rhs.0);
+        let mut product = 0;
+        for _ in 0..8 {
+            // all ones if the low bit of b is set, zero otherwise
+            product ^= a & 0u8.wrapping_sub(b & 1);
+            let carry = 0u8.wrapping_sub(a >> 7);
+            a = (a << 1) ^ (AES_POLYNOMIAL & carry);
+            b >>= 1;
+        }
+        Gf256(product)
+    }
+}
+
+impl Div for Gf256 {
+    type Output = Gf256;
+
+    /// Divides two elements.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Gf256) -> Gf256 {
+        self * rhs.inverse().expect("division by zero in GF(256)")
+    }
+}
+
+impl AddAssign for Gf256 {
+    fn add_assign(&mut self, rhs: Gf256) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Gf256 {
+    fn sub_assign(&mut self, rhs: Gf256) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Gf256 {
+    fn mul_assign(&mut self, rhs: Gf256) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Gf256 {
+    fn div_assign(&mut self, rhs: Gf256) {
+        *self = *self / rhs;
+    }
+}
+
+impl Field for Gf256 {
+    const MAX_SHARES: u64 = 255;
+
+    fn zero() -> Self {
+        Gf256::ZERO
+    }
+
+    fn one() -> Self {
+        Gf256::ONE
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Gf256::inverse(*self)
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Gf256(rng.gen())
+    }
+
+    /// Maps the low byte of the integer into the field.
+    fn from_u64(value: u64) -> Self {
+        Gf256(value as u8)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(u64::from(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_non_zero_element_has_an_inverse() {
+        assert_eq!(Gf256::ZERO.inverse(), None);
+        for a in 1..=u8::MAX {
+            let a = Gf256(a);
+            assert_eq!(a * a.inverse().unwrap(), Gf256::ONE, "{a}");
+        }
+    }
+
+    #[test]
+    fn multiplies_as_aes() {
+        // the examples of FIPS 197, section 4.2
+        assert_eq!(Gf256(0x57) * Gf256(0x83), Gf256(0xc1));
+        assert_eq!(Gf256(0x57) * Gf256(0x13), Gf256(0xfe));
+        assert_eq!(Gf256(0x57) * Gf256(0x02), Gf256(0xae));
+    }
+
+    #[test]
+    fn multiplies_every_pair_as_the_reduced_polynomial_product() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                // the carry-less product, of degree up to 14, reduced by x⁸ + x⁴ + x³ + x + 1
+                let mut product = (0..8)
+                    .filter(|i| b >> i & 1 == 1)
+                    .fold(0u16, |product, i| product ^ u16::from(a) << i);
+                for i in (8..15).rev() {
+                    if product >> i & 1 == 1 {
+                        product ^= 0x11b << (i - 8);
+                    }
+                }
+                assert_eq!(Gf256(a) * Gf256(b), Gf256(product as u8), "{a} * {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn adds_as_xor() {
+        assert_eq!(Gf256(0x57) + Gf256(0x83), Gf256(0xd4));
+        assert_eq!(Gf256(0x57) - Gf256(0x83), Gf256(0xd4));
+        assert_eq!(-Gf256(0x57), Gf256(0x57));
+    }
+
+    #[test]
+    fn divides_by_multiplying_by_the_inverse() {
+        assert_eq!(Gf256(0xc1) / Gf256(0x83), Gf256(0x57));
+    }
+}
@@ -0,0 +1,238 @@
+//! Arithmetic over the finite field GF(2¹⁶).
+//!
+//! Elements are 16-bit words, seen as polynomials over GF(2) of degree less than 16,
+//! reduced modulo the polynomial x¹⁶ + x¹² + x³ + x + 1.
+//! Addition and subtraction are both XOR,
+//! and every non-zero element has a multiplicative inverse.
+//! The field has 65,535 non-zero elements, hence it supports that many shares,
+//! far beyond the 255 of GF(2⁸), see [`crate::gf256`].
+
+// Field addition is XOR and division multiplies by the inverse.
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use rand::Rng;
+
+use crate::field::Field;
+
+/// The low 16 bits of the polynomial x¹⁶ + x¹² + x³ + x + 1.
+const POLYNOMIAL: u16 = 0x100b;
+
+/// An element of GF(2¹⁶).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Gf65536(pub u16);
+
+impl Gf65536 {
+    /// The additive identity.
+    pub const ZERO: Gf65536 = Gf65536(0);
+
+    /// The multiplicative identity.
+    pub const ONE: Gf65536 = Gf65536(1);
+
+    /// Raises the element to the given power by square-and-multiply.
+    pub fn pow(self, mut exponent: u32) -> Gf65536 {
+        let mut base = self;
+        let mut result = Gf65536::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse of the element,
+    /// or `None` for zero, which has no inverse.
+    ///
+    /// The multiplicative group has order 65,535, hence the inverse is a⁶⁵⁵³⁴.
+    pub fn inverse(self) -> Option<Gf65536> {
+        if self == Gf65536::ZERO {
+            None
+        } else {
+            Some(self.pow(65_534))
+        }
+    }
+}
+
+impl From<u16> for Gf65536 {
+    fn from(value: u16) -> Self {
+        Gf65536(value)
+    }
+}
+
+impl From<Gf65536> for u16 {
+    fn from(value: Gf65536) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Gf65536 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#06x}", self.0)
+    }
+}
+
+impl Add for Gf65536 {
+    type Output = Gf65536;
+
+    fn add(self, rhs: Gf65536) -> Gf65536 {
+        Gf65536(self.0 ^ rhs.0)
+    }
+}
+
+impl Sub for Gf65536 {
+    type Output = Gf65536;
+
+    fn sub(self, rhs: Gf65536) -> Gf65536 {
+        Gf65536(self.0 ^ rhs.0)
+    }
+}
+
+impl Neg for Gf65536 {
+    type Output = Gf65536;
+
+    fn neg(self) -> Gf65536 {
+        self
+    }
+}
+
+impl Mul for Gf65536 {
+    type Output = Gf65536;
+
+    /// Multiplies two elements by shift-and-add over the 16 bits of `rhs`,
+    /// reducing by the field polynomial whenever the degree reaches 16.
+    /// The additions and reductions are masked rather than branched on,
+    /// so that the time taken does not depend on the elements, as in [`crate::gf256`].
+    fn mul(self, rhs: Gf65536) -> Gf65536 {
+        let (mut a, mut b) = (self.0, rhs.0);
+        let mut product = 0;
+        for _ in 0..16 {
+            // all ones if the low bit of b is set, zero otherwise
+            product ^= a & 0u16.wrapping_sub(b & 1);
+            let carry = 0u16.wrapping_sub(a >> 15);
+            a = (a << 1) ^ (POLYNOMIAL & carry);
+            b >>= 1;
+        }
+        Gf65536(product)
+    }
+}
+
+impl Div for Gf65536 {
+    type Output = Gf65536;
+
+    /// Divides two elements.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Gf65536) -> Gf65536 {
+        self * rhs.inverse().expect("division by zero in GF(65536)")
+    }
+}
+
+impl AddAssign for Gf65536 {
+    fn add_assign(&mut self, rhs: Gf65536) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Gf65536 {
+    fn sub_assign(&mut self, rhs: Gf65536) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Gf65536 {
+    fn mul_assign(&mut self, rhs: Gf65536) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Gf65536 {
+    fn div_assign(&mut self, rhs: Gf65536) {
+        *self = *self / rhs;
+    }
+}
+
+impl Field for Gf65536 {
+    const MAX_SHARES: u64 = 65_535;
+
+    fn zero() -> Self {
+        Gf65536::ZERO
+    }
+
+    fn one() -> Self {
+        Gf65536::ONE
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Gf65536::inverse(*self)
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Gf65536(rng.gen())
+    }
+
+    /// Maps the low 16 bits of the integer into the field.
+    fn from_u64(value: u64) -> Self {
+        Gf65536(value as u16)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(u64::from(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_non_zero_element_has_an_inverse() {
+        assert_eq!(Gf65536::ZERO.inverse(), None);
+        for a in 1..=u16::MAX {
+            let a = Gf65536(a);
+            assert_eq!(a * a.inverse().unwrap(), Gf65536::ONE, "{a}");
+        }
+    }
+
+    #[test]
+    fn reduces_by_the_field_polynomial() {
+        // x¹⁵ · x = x¹⁶ = x¹² + x³ + x + 1
+        assert_eq!(Gf65536(0x8000) * Gf65536(0x0002), Gf65536(POLYNOMIAL));
+        assert_eq!(Gf65536(0x1234) * Gf65536::ONE, Gf65536(0x1234));
+        assert_eq!(Gf65536(0x1234) * Gf65536::ZERO, Gf65536::ZERO);
+    }
+
+    #[test]
+    fn multiplies_as_the_reduced_polynomial_product() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10_000 {
+            let (a, b): (u16, u16) = (rng.gen(), rng.gen());
+            // the carry-less product, of degree up to 30, reduced by x¹⁶ + x¹² + x³ + x + 1
+            let mut product = (0..16)
+                .filter(|i| b >> i & 1 == 1)
+                .fold(0u32, |product, i| product ^ u32::from(a) << i);
+            for i in (16..31).rev() {
+                if product >> i & 1 == 1 {
+                    product ^= 0x1100b << (i - 16);
+                }
+            }
+            assert_eq!(
+                Gf65536(a) * Gf65536(b),
+                Gf65536(product as u16),
+                "{a} * {b}"
+            );
+        }
+    }
+
+    #[test]
+    fn multiplication_distributes_over_addition() {
+        let (a, b, c) = (Gf65536(0xbeef), Gf65536(0x1234), Gf65536(0xcafe));
+        assert_eq!(a * (b + c), a * b + a * c);
+    }
+}
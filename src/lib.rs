@@ -0,0 +1,632 @@
+//! Shamir's Secret Sharing.
+//!
+//! A secret is split into `n` shares, such that any `k` of them
+//! reconstruct the secret, while fewer than `k` reveal nothing about it.
+//!
+//! The secret is the constant term of a random polynomial of degree `k - 1`,
+//! see the [`polynomial`] module, and every share is a point on that polynomial.
+//! Reconstruction uses Lagrange interpolation, in the barycentric form
+//! of the [`barycentric`] module, to recover the value of the polynomial at x = 0.
+//!
+//! The polynomials are defined over a finite field, see the [`field`] module.
+//! By default this is GF(2⁸), see the [`gf256`] module,
+//! GF(2¹⁶) supports more than 255 shares, see the [`gf65536`] module,
+//! and prime fields GF(p) are available in the [`prime`] module.
+//! Secrets of any length are shared byte by byte,
+//! with an independent polynomial for every byte,
+//! at x = 1, 2, ..., n or at the x-coordinates of [`split_at`].
+//! With the `bigint` feature, the `bigint` module instead shares
+//! a big integer secret as a single element of a large prime field.
+//! The parameters of every split are checked by the [`validation`] module,
+//! and the [`builder`] module validates them once,
+//! before sharing any number of secrets with them.
+//! Given more shares than the threshold, the [`berlekamp_welch`] module
+//! identifies the incorrect ones, and reconstructs the secret without them.
+//! The [`dealerless`] module generates a shared secret without a dealer,
+//! and the [`resharing`] module reshares a secret to a new threshold or set of participants
+//! without reconstructing it.
+//! With the `vss` feature, the `feldman` module lets every participant
+//! verify its share against public commitments, over the `ristretto` scalar field,
+//! and the `pedersen` module blinds the commitments to hide the secret unconditionally,
+//! while the `pvss` module encrypts the shares to the participants,
+//! so that anyone verifies them.
+//! The [`fingerprint`] module verifies the reconstruction of a secret,
+//! and the [`padding`] module hides its length.
+//! With the `deterministic` feature, the `deterministic` module
+//! derives the polynomials from a seed, to regenerate the same shares.
+//! The [`real`] module offers the same scheme over floating-point numbers,
+//! which is only meant for illustrations,
+//! and the [`chart`] module renders them in memory, as SVG documents or PNG images.
+//!
+//! Every failure is reported as an [`Error`], whose variants tell apart its causes.
+
+pub mod barycentric;
+pub mod berlekamp_welch;
+#[cfg(feature = "bigint")]
+pub mod bigint;
+pub mod builder;
+pub mod chart;
+pub mod dealerless;
+#[cfg(feature = "deterministic")]
+pub mod deterministic;
+pub mod error;
+#[cfg(feature = "vss")]
+pub mod feldman;
+pub mod field;
+pub mod fingerprint;
+pub mod gf256;
+pub mod gf65536;
+pub mod padding;
+#[cfg(feature = "vss")]
+pub mod pedersen;
+pub mod polynomial;
+pub mod prime;
+#[cfg(feature = "vss")]
+pub mod pvss;
+pub mod real;
+pub mod resharing;
+#[cfg(feature = "vss")]
+pub mod ristretto;
+pub mod validation;
+
+use barycentric::Barycentric;
+pub use error::Error;
+use field::Field;
+use fingerprint::Fingerprint;
+use gf256::Gf256;
+use polynomial::Polynomial;
+use rand::{CryptoRng, RngCore};
+use validation::validate_parameters;
+
+/// A share of a secret over the field `F`, GF(2⁸) by default.
+///
+/// A share holds one point on each of the polynomials hiding the bytes of the secret,
+/// all of them evaluated at the same x-coordinate,
+/// along with the parameters of the split it belongs to,
+/// an optional label, such as the name of its holder,
+/// an optional fingerprint of the secret, see the [`fingerprint`] module,
+/// and whether the secret is padded, see the [`padding`] module.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Share<F = Gf256> {
+    x: F,
+    y: Vec<F>,
+    threshold: usize,
+    total: usize,
+    label: Option<String>,
+    fingerprint: Option<Fingerprint>,
+    padded: bool,
+}
+
+impl<F> Share<F> {
+    /// Creates a share of a split of `total` shares, any `threshold` of which reconstruct the secret.
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The x-coordinate of the share, never zero.
+    /// * `y` - The y-coordinates of the share, one for every byte of the secret.
+    /// * `threshold` - The number of shares needed to reconstruct the secret.
+    /// * `total` - The number of shares of the split.
+    pub fn new(x: F, y: Vec<F>, threshold: usize, total: usize) -> Self {
+        Share {
+            x,
+            y,
+            threshold,
+            total,
+            label: None,
+            fingerprint: None,
+            padded: false,
+        }
+    }
+
+    /// Labels the share, for example with the name of its holder.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Embeds the fingerprint of the secret in the share,
+    /// so that [`combine`] verifies the reconstructed secret.
+    pub fn with_fingerprint(mut self, fingerprint: Fingerprint) -> Self {
+        self.fingerprint = Some(fingerprint);
+        self
+    }
+
+    /// Marks the secret of the share as padded,
+    /// so that [`combine`] removes the padding of the reconstructed secret.
+    pub fn with_padding(mut self) -> Self {
+        self.padded = true;
+        self
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> &F {
+        &self.x
+    }
+
+    /// Returns the y-coordinates of the share, one for every byte of the secret.
+    pub fn y(&self) -> &[F] {
+        &self.y
+    }
+
+    /// Returns the number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the number of shares of the split.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the label of the share, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns the fingerprint of the secret embedded in the share, if any.
+    pub fn fingerprint(&self) -> Option<&Fingerprint> {
+        self.fingerprint.as_ref()
+    }
+
+    /// Returns whether the secret of the share is padded.
+    pub fn is_padded(&self) -> bool {
+        self.padded
+    }
+}
+
+/// A secret, the bytes given by the values of the polynomials at x = 0.
+pub type Secret = Vec<u8>;
+
+/// Splits a secret into `n` shares over GF(2⁸), any `k` of which reconstruct it.
+///
+/// See [`split_over`] for the details and errors.
+pub fn split(secret: &[u8], k: usize, n: usize) -> Result<Vec<Share>, Error> {
+    split_over::<Gf256>(secret, k, n)
+}
+
+/// Splits a secret into `n` shares over GF(2⁸), any `k` of which reconstruct it,
+/// drawing the coefficients of the polynomials from the given random number generator.
+///
+/// See [`split_over_with_rng`] for the details and errors.
+pub fn split_with_rng<R: CryptoRng + RngCore + ?Sized>(
+    secret: &[u8],
+    k: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<Share>, Error> {
+    split_over_with_rng::<Gf256, R>(secret, k, n, rng)
+}
+
+/// Splits a secret into `n` shares over the field `F`, any `k` of which reconstruct it.
+///
+/// Every byte of the secret is the constant term of its own polynomial,
+/// whose other coefficients are drawn uniformly from the field
+/// with the thread-local random number generator.
+/// The shares are evaluated at x = 1, 2, ..., n.
+///
+/// ## Arguments
+///
+/// * `secret` - The secret to split.
+/// * `k` - The number of shares needed to reconstruct the secret.
+/// * `n` - The number of shares to generate.
+///
+/// ## Errors
+///
+/// Returns an error if the secret is empty, if the field cannot hold every byte,
+/// if `k` is zero or greater than `n`,
+/// or if `n` is greater than the number of non-zero elements of the field,
+/// see the [`validation`] module.
+pub fn split_over<F: Field>(secret: &[u8], k: usize, n: usize) -> Result<Vec<Share<F>>, Error> {
+    split_over_with_rng(secret, k, n, &mut rand::thread_rng())
+}
+
+/// Splits a secret into `n` shares over the field `F`, any `k` of which reconstruct it,
+/// drawing the coefficients of the polynomials from the given random number generator.
+///
+/// Any cryptographically secure generator can be used,
+/// such as the operating system's or a hardware one.
+/// See [`split_over`] for the details and errors.
+pub fn split_over_with_rng<F: Field, R: CryptoRng + RngCore + ?Sized>(
+    secret: &[u8],
+    k: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<Share<F>>, Error> {
+    validation::validate_secret(secret)?;
+    validation::validate_byte_field::<F>()?;
+    let secret: Vec<F> = secret
+        .iter()
+        .map(|&byte| F::from_u64(byte.into()))
+        .collect();
+    split_elements(&secret, k, n, rng)
+}
+
+/// Splits a secret into shares over the field `F` at the given x-coordinates,
+/// any `k` of which reconstruct it.
+///
+/// Instead of x = 1, 2, ..., n, one share is evaluated at every x-coordinate, in order,
+/// so that the shares can be tied to an external identity scheme,
+/// such as the IDs of the participants.
+/// See [`split_over`] for the other details.
+///
+/// ## Arguments
+///
+/// * `secret` - The secret to split.
+/// * `k` - The number of shares needed to reconstruct the secret.
+/// * `x` - The x-coordinates of the shares to generate.
+///
+/// ## Errors
+///
+/// Returns an error if an x-coordinate is zero, which would reveal the secret,
+/// or if two of them are the same,
+/// in addition to the errors of [`split_over`] with as many shares as x-coordinates.
+pub fn split_at<F: Field>(secret: &[u8], k: usize, x: &[F]) -> Result<Vec<Share<F>>, Error> {
+    split_at_with_rng(secret, k, x, &mut rand::thread_rng())
+}
+
+/// Splits a secret into shares over the field `F` at the given x-coordinates,
+/// any `k` of which reconstruct it,
+/// drawing the coefficients of the polynomials from the given random number generator.
+///
+/// See [`split_at`] for the details and errors.
+pub fn split_at_with_rng<F: Field, R: CryptoRng + RngCore + ?Sized>(
+    secret: &[u8],
+    k: usize,
+    x: &[F],
+    rng: &mut R,
+) -> Result<Vec<Share<F>>, Error> {
+    validation::validate_secret(secret)?;
+    validation::validate_byte_field::<F>()?;
+    let secret: Vec<F> = secret
+        .iter()
+        .map(|&byte| F::from_u64(byte.into()))
+        .collect();
+    validate_parameters::<F>(k, x.len())?;
+    validation::validate_x_coordinates(x)?;
+    Ok(split_elements_at(&secret, k, x, rng))
+}
+
+/// Splits a secret made of field elements into `n` shares, any `k` of which reconstruct it.
+///
+/// Every element of the secret is the constant term of its own polynomial.
+/// See [`split_over`] for the details and errors.
+pub(crate) fn split_elements<F: Field, R: CryptoRng + RngCore + ?Sized>(
+    secret: &[F],
+    k: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<Share<F>>, Error> {
+    validate_parameters::<F>(k, n)?;
+    let x: Vec<F> = (1..=n as u64).map(F::from_u64).collect();
+    Ok(split_elements_at(secret, k, &x, rng))
+}
+
+/// Splits a secret made of field elements into shares at the given x-coordinates,
+/// any `k` of which reconstruct it.
+///
+/// The parameters and the x-coordinates must already be validated,
+/// see [`split_at`].
+fn split_elements_at<F: Field, R: CryptoRng + RngCore + ?Sized>(
+    secret: &[F],
+    k: usize,
+    x: &[F],
+    rng: &mut R,
+) -> Vec<Share<F>> {
+    let n = x.len();
+    let polynomials: Vec<Polynomial<F>> = secret
+        .iter()
+        .map(|element| Polynomial::random(element.clone(), k - 1, rng))
+        .collect();
+
+    x.iter()
+        .map(|x| {
+            let y = polynomials
+                .iter()
+                .map(|polynomial| polynomial.evaluate(x))
+                .collect();
+            Share::new(x.clone(), y, k, n)
+        })
+        .collect()
+}
+
+/// Combines shares to reconstruct the secret.
+///
+/// Every byte of the secret is recovered by Lagrange interpolation
+/// of the corresponding y-coordinates of the shares at x = 0.
+/// At least `k` shares are needed to recover the secret of a threshold `k` split,
+/// fewer shares are rejected.
+/// More shares must all lie on the same polynomials,
+/// otherwise the incorrect ones are identified in the error,
+/// as long as at most ⌊(n - k) / 2⌋ of the `n` shares are incorrect,
+/// which requires at least `k + 2` shares, see [`berlekamp_welch::inconsistent_shares`];
+/// if they cannot be identified, the error only reports that some shares are incorrect.
+///
+/// If the shares are padded, the padding of the reconstructed secret is removed,
+/// and if they carry a fingerprint of the secret,
+/// the reconstructed secret is verified against it.
+///
+/// ## Errors
+///
+/// Returns an error if the field cannot hold every byte,
+/// if the shares are inconsistent, see [`interpolate_at`],
+/// if a reconstructed value is not a byte,
+/// if the padding of a padded secret is invalid,
+/// or if the reconstructed secret does not match the fingerprint of the shares.
+pub fn combine<F: Field>(shares: &[Share<F>]) -> Result<Secret, Error> {
+    validation::validate_byte_field::<F>()?;
+    to_secret(shares, interpolate_at(shares, &F::zero())?)
+}
+
+/// Converts the elements of a secret reconstructed from shares into its bytes,
+/// removing its padding and verifying its fingerprint, if the shares carry them,
+/// see [`combine`].
+pub(crate) fn to_secret<F: Field>(shares: &[Share<F>], secret: Vec<F>) -> Result<Secret, Error> {
+    let mut secret = secret
+        .into_iter()
+        .map(|value| {
+            value
+                .to_u64()
+                .and_then(|value| u8::try_from(value).ok())
+                .ok_or_else(|| Error::Decode(format!("{value:?}")))
+        })
+        .collect::<Result<Secret, _>>()?;
+    if shares[0].is_padded() {
+        let length = padding::unpad(&secret)?.len();
+        secret.truncate(length);
+    }
+    match shares[0].fingerprint() {
+        Some(fingerprint) if !fingerprint.verify(&secret) => Err(Error::IncorrectSecret),
+        _ => Ok(secret),
+    }
+}
+
+/// Evaluates the polynomials hidden by the shares at `x`,
+/// one value for every element of the secret.
+///
+/// The values are recovered by Lagrange interpolation of the shares at `x`,
+/// in barycentric form, see the [`barycentric`] module,
+/// hence they are the elements of the secret at x = 0,
+/// and the y-coordinates of another share of the same split at any other x,
+/// for example to replace a lost share.
+///
+/// ## Errors
+///
+/// Returns an error if there are no shares,
+/// if a share has the x-coordinate zero or two shares have the same x-coordinate,
+/// if the shares hold no y-coordinate or have different lengths,
+/// if they were split with different thresholds or numbers of shares,
+/// if they carry different fingerprints or paddings,
+/// if there are fewer shares than their threshold, see [`validation::validate_shares`],
+/// or if more shares than the threshold are given and some of them are inconsistent
+/// with the others, see [`berlekamp_welch::inconsistent_shares`].
+pub fn interpolate_at<F: Field>(shares: &[Share<F>], x: &F) -> Result<Vec<F>, Error> {
+    validation::validate_shares(shares)?;
+    let inconsistent = berlekamp_welch::find_inconsistent_shares(shares)?;
+    if !inconsistent.is_empty() {
+        return Err(Error::InconsistentShares(inconsistent));
+    }
+    let length = shares[0].y.len();
+
+    // the Lagrange basis at x only depends on the x-coordinates,
+    // hence it is shared by every element of the secret
+    let x_coordinates: Vec<F> = shares.iter().map(|share| share.x.clone()).collect();
+    let bases = Barycentric::new(&x_coordinates)?.bases(x);
+
+    let values = (0..length)
+        .map(|i| {
+            shares
+                .iter()
+                .zip(&bases)
+                .fold(F::zero(), |value, (share, basis)| {
+                    value + share.y[i].clone() * basis.clone()
+                })
+        })
+        .collect();
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::ShamirBuilder;
+    use crate::gf65536::Gf65536;
+    use crate::prime::{Fp, Fp257, Mersenne61};
+
+    const SECRET: &[u8] = b"correct horse battery staple";
+
+    /// Checks that every window of `k` shares of a split over `F` reconstructs the secret.
+    fn round_trip<F: Field>(k: usize, n: usize) {
+        let shares = split_over::<F>(SECRET, k, n).unwrap();
+        assert_eq!(shares.len(), n);
+        for window in shares.windows(k) {
+            assert_eq!(combine(window).unwrap(), SECRET);
+        }
+        assert_eq!(combine(&shares).unwrap(), SECRET);
+    }
+
+    #[test]
+    fn round_trips_over_every_field() {
+        round_trip::<Gf256>(3, 5);
+        round_trip::<Gf65536>(3, 5);
+        round_trip::<Fp257>(3, 5);
+        round_trip::<Mersenne61>(3, 5);
+    }
+
+    #[test]
+    fn round_trips_every_byte() {
+        let secret: Vec<u8> = (0..=u8::MAX).collect();
+        let shares = split(&secret, 2, 3).unwrap();
+        assert_eq!(combine(&shares[1..]).unwrap(), secret);
+    }
+
+    #[test]
+    fn rejects_fields_too_small_for_a_byte() {
+        assert!(matches!(
+            split_over::<Fp<31>>(SECRET, 3, 5),
+            Err(Error::FieldTooSmall)
+        ));
+        let shares: Vec<Share<Fp<31>>> = (1..=3)
+            .map(|x| Share::new(Fp::new(x), vec![Fp::new(x)], 3, 3))
+            .collect();
+        assert!(matches!(combine(&shares), Err(Error::FieldTooSmall)));
+    }
+
+    #[test]
+    fn rejects_shares_of_different_splits() {
+        let shares = split(SECRET, 2, 3).unwrap();
+        let other = split(SECRET, 2, 4).unwrap();
+        assert!(matches!(
+            combine(&[shares[0].clone(), other[1].clone()]),
+            Err(Error::MixedShareSets { .. })
+        ));
+        let other = split(SECRET, 3, 3).unwrap();
+        assert!(matches!(
+            combine(&[shares[0].clone(), other[1].clone()]),
+            Err(Error::ThresholdMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn identifies_an_incorrect_share() {
+        let mut shares = split(SECRET, 3, 5).unwrap();
+        let mut y = shares[2].y().to_vec();
+        y[0] += Gf256(1);
+        shares[2] = Share::new(*shares[2].x(), y, 3, 5);
+        assert!(matches!(
+            combine(&shares),
+            Err(Error::InconsistentShares(indices)) if indices == [2]
+        ));
+        assert!(matches!(
+            combine(&shares[..4]),
+            Err(Error::UnidentifiableShares)
+        ));
+        assert_eq!(
+            combine(
+                &shares[..2]
+                    .iter()
+                    .chain(&shares[3..])
+                    .cloned()
+                    .collect::<Vec<_>>()
+            )
+            .unwrap(),
+            SECRET
+        );
+    }
+
+    #[test]
+    fn verifies_the_secret_against_its_fingerprint() {
+        let shamir = ShamirBuilder::new()
+            .threshold(2)
+            .shares(3)
+            .fingerprint()
+            .build()
+            .unwrap();
+        let mut shares = shamir.split(SECRET).unwrap();
+        let other = shamir.split(SECRET).unwrap();
+        assert!(matches!(
+            combine(&[shares[0].clone(), other[1].clone()]),
+            Err(Error::FingerprintMismatch)
+        ));
+        let mut y = shares[1].y().to_vec();
+        y[0] += Gf256(1);
+        let fingerprint = shares[1].fingerprint().unwrap().clone();
+        shares[1] = Share::new(*shares[1].x(), y, 2, 3).with_fingerprint(fingerprint);
+        assert!(matches!(combine(&shares[..2]), Err(Error::IncorrectSecret)));
+    }
+
+    #[test]
+    fn splits_at_the_given_x_coordinates() {
+        let x: Vec<Gf256> = [7, 42, 200].map(Gf256).to_vec();
+        let shares = split_at(SECRET, 2, &x).unwrap();
+        assert_eq!(shares.iter().map(|share| *share.x()).collect::<Vec<_>>(), x);
+        assert_eq!(combine(&shares[1..]).unwrap(), SECRET);
+        assert!(matches!(
+            split_at(SECRET, 2, &[Gf256(0), Gf256(1)]),
+            Err(Error::ZeroXCoordinate)
+        ));
+        assert!(matches!(
+            split_at(SECRET, 2, &[Gf256(1), Gf256(1)]),
+            Err(Error::DuplicateShares)
+        ));
+    }
+
+    #[test]
+    fn interpolates_the_secret_at_zero_and_other_shares_elsewhere() {
+        let shares = split(SECRET, 3, 5).unwrap();
+        let secret: Vec<Gf256> = SECRET.iter().map(|&byte| Gf256(byte)).collect();
+        assert_eq!(interpolate_at(&shares[..3], &Gf256(0)).unwrap(), secret);
+        assert_eq!(
+            interpolate_at(&shares[..3], shares[4].x()).unwrap(),
+            shares[4].y()
+        );
+    }
+
+    #[test]
+    fn round_trips_with_two_shares_and_all_shares() {
+        for (k, n) in [(2, 5), (5, 5), (1, 1)] {
+            let shares = split(SECRET, k, n).unwrap();
+            assert_eq!(combine(&shares[n - k..]).unwrap(), SECRET);
+        }
+    }
+
+    #[test]
+    fn round_trips_with_the_maximum_number_of_shares() {
+        let shares = split(SECRET, 3, 255).unwrap();
+        assert_eq!(combine(&shares[252..]).unwrap(), SECRET);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        assert!(matches!(split(b"", 2, 3), Err(Error::EmptySecret)));
+        assert!(matches!(split(SECRET, 0, 3), Err(Error::ZeroThreshold)));
+        assert!(matches!(
+            split(SECRET, 4, 3),
+            Err(Error::ThresholdAboveShares { .. })
+        ));
+        assert!(matches!(
+            split(SECRET, 2, 256),
+            Err(Error::TooManyShares { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_fewer_shares_than_the_threshold() {
+        let shares = split(SECRET, 3, 5).unwrap();
+        assert!(matches!(
+            combine(&shares[..2]),
+            Err(Error::InsufficientShares {
+                threshold: 3,
+                shares: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_no_or_duplicate_shares() {
+        let shares = split(SECRET, 2, 3).unwrap();
+        assert!(matches!(combine::<Gf256>(&[]), Err(Error::NoShares)));
+        let duplicated = [shares[0].clone(), shares[0].clone()];
+        assert!(matches!(combine(&duplicated), Err(Error::DuplicateShares)));
+    }
+
+    #[test]
+    fn rejects_a_forged_share_at_zero() {
+        let shares = split(SECRET, 3, 5).unwrap();
+        let forged = Share::new(Gf256(0), b"xyz".map(Gf256).to_vec(), 3, 5);
+        let shares = [forged, shares[1].clone(), shares[2].clone()];
+        assert!(matches!(combine(&shares), Err(Error::ZeroXCoordinate)));
+        assert!(matches!(
+            interpolate_at(&shares, &Gf256(7)),
+            Err(Error::ZeroXCoordinate)
+        ));
+    }
+
+    #[test]
+    fn rejects_empty_shares() {
+        let shares: Vec<Share> = (1..=2)
+            .map(|x| Share::new(Gf256(x), Vec::new(), 2, 2))
+            .collect();
+        assert!(matches!(combine(&shares), Err(Error::EmptyShares)));
+    }
+}
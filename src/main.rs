@@ -1,185 +1,63 @@
-use std::convert::identity;
-use std::error::Error;
 use std::fs::create_dir_all;
-use std::ops::Range;
 use std::path::{Path, PathBuf};
 
-use plotters::coord::types::RangedCoordf32;
+use plotters::coord::Shift;
 use plotters::prelude::*;
-
-const DIMENSIONS: (u32, u32) = (640, 480);
-
-/// Creates a chart with a polynomial, its shares and the secret.
-/// The chart is saved to a file.
-///
-/// ## Arguments
-///
-/// * `filename` - The name of the file to save the chart to.
-/// * `title` - The title of the chart.
-/// * `dimensions` - The dimensions of the chart.
-/// * `x_range` - The range of the x-axis.
-/// * `y_range` - The range of the y-axis.
-/// * `polynomial` - The polynomial to plot.
-/// * `polynomial_str` - The string representation of the polynomial.
-/// * `shares_x` - The x-coordinates of the shares.
-/// * `secret` - Whether to plot the secret.
-#[allow(clippy::too_many_arguments)]
-fn create_chart(
-    filename: &PathBuf,
-    title: &str,
-    dimensions: (u32, u32),
-    x_range: Range<f32>,
-    y_range: Range<f32>,
-    polynomial: impl Fn(f32) -> f32,
-    polynomial_str: &str,
-    shares_x: &[f32],
-    secret: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let root_area = SVGBackend::new(filename, dimensions).into_drawing_area();
-    root_area.fill(&TRANSPARENT)?;
-
-    let mut chart = ChartBuilder::on(&root_area)
-        .caption(title, ("sans-serif", 32).into_font())
-        .margin(5)
-        .x_label_area_size(35)
-        .y_label_area_size(40)
-        .build_cartesian_2d(x_range.clone(), y_range.clone())?;
-
-    let x_labels_count = shares_x.len() + secret as usize;
-    chart
-        .configure_mesh()
-        .x_labels(x_labels_count)
-        .y_labels(5)
-        .disable_mesh()
-        .x_label_formatter(&|v| format!("{:.0}", v))
-        .y_label_formatter(&|v| format!("{:.0}", v))
-        .draw()?;
-
-    // add vertical line at x=0
-    let vertical_line = LineSeries::new(vec![(0.0, y_range.start), (0.0, y_range.end)], BLACK);
-
-    // Draw the line on the chart
-    chart.draw_series(vertical_line)?;
-
-    // add the polynomial, shares and secret to the chart
-    draw_polynomial(&mut chart, &polynomial, polynomial_str, x_range)?;
-    draw_shares(&mut chart, &polynomial, shares_x)?;
-    if secret {
-        draw_secret(&mut chart, &polynomial)?;
+use shamir_secret_sharing::chart::{
+    draw_axes, draw_band, draw_caption, draw_coefficients, draw_corrupted_share, draw_legend,
+    draw_points, draw_polynomial, draw_secret, draw_shares, draw_step_caption, interpolate,
+    register_font, render_svg, render_to_string, shares_of, ChartSpec, FileSink, Mode, PlotSink,
+};
+use shamir_secret_sharing::field::Field;
+use shamir_secret_sharing::prime::Fp;
+use shamir_secret_sharing::real::{Polynomial, Share};
+use shamir_secret_sharing::{interpolate_at, Error};
+
+/// The prime field of the finite-field chart, small enough to plot all its points.
+type Fp31 = Fp<31>;
+
+/// The directory the charts of a mode are saved to.
+fn directory(mode: Mode) -> PathBuf {
+    match mode {
+        Mode::Full => PathBuf::from("plots"),
+        Mode::Compact => Path::new("plots").join("thumbnails"),
+        Mode::Annotated => Path::new("plots").join("annotated"),
     }
-
-    chart
-        .configure_series_labels()
-        .position(SeriesLabelPosition::LowerRight)
-        .border_style(BLACK)
-        .background_style(WHITE.mix(0.8))
-        .legend_area_size(10)
-        .draw()?;
-
-    Ok(())
 }
 
-/// Draws a polynomial on a chart.
-/// The polynomial is drawn as a line.
-/// The chart is updated in place.
-/// The polynomial is labeled in the legend, drawn in blue with
-/// a width of 2 and stepsize of 1e-3.
-fn draw_polynomial<F>(
-    chart: &mut ChartContext<SVGBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
-    polynomial: F,
-    polynomial_str: &str,
-    x_range: Range<f32>,
-) -> Result<(), Box<dyn std::error::Error>>
-where
-    F: Fn(f32) -> f32,
-{
-    let points: Vec<(f32, f32)> = x_range
-        .step(1e-3)
-        .values()
-        .map(|x| (x, polynomial(x)))
-        .collect();
-    chart
-        .draw_series(LineSeries::new(points.iter().copied(), BLUE))?
-        .label(polynomial_str)
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], BLUE.stroke_width(2)));
-    Ok(())
-}
-
-/// Draws shares on a chart.
-/// The shares are drawn as points.
-/// The chart is updated in place.
-/// The shares are labeled in the legend, drawn in red with a size of 5.
-fn draw_shares<F>(
-    chart: &mut ChartContext<SVGBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
-    polynomial: F,
-    shares_x: &[f32],
-) -> Result<(), Box<dyn std::error::Error>>
+/// Renders an SVG chart in memory and writes it to a sink, named after the given path,
+/// see [`render_svg`].
+fn render<F>(
+    sink: &mut dyn PlotSink,
+    path: &Path,
+    dimensions: (u32, u32),
+    draw: F,
+) -> Result<(), Error>
 where
-    F: Fn(f32) -> f32,
+    F: FnOnce(&DrawingArea<SVGBackend, Shift>) -> Result<(), Error>,
 {
-    let shares: Vec<(f32, f32)> = shares_x.iter().map(|&x| (x, polynomial(x))).collect();
-    chart
-        .draw_series(PointSeries::of_element(
-            shares,
-            5,
-            RED.filled(),
-            &|coord, size, style| {
-                EmptyElement::at(coord)
-                    + Circle::new((0, 0), size, style)
-                    + Text::new(format!("{:.1?}", coord), (1, 10), ("sans-serif", 15))
-            },
-        ))?
-        .label("Shares")
-        .legend(|(x, y)| Circle::new((x, y), 5, RED.filled()));
-    Ok(())
-}
-
-/// Draws the secret on a chart.
-/// The secret is drawn as a point.
-/// The chart is updated in place.
-/// The secret is labeled in the legend, drawn in green with a size of 5.
-fn draw_secret<F>(
-    chart: &mut ChartContext<SVGBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
-    polynomial: F,
-) -> Result<(), Box<dyn std::error::Error>>
-where
-    F: Fn(f32) -> f32,
-{
-    let secret = (0.0f32, polynomial(0.0));
-    chart
-        .draw_series(PointSeries::of_element(
-            std::iter::once(secret),
-            5,
-            GREEN.filled(),
-            &|coord, size, style| {
-                EmptyElement::at(coord)
-                    + Circle::new((0, 0), size, style)
-                    + Text::new(format!("{:?}", coord), (1, 10), ("sans-serif", 15))
-            },
-        ))?
-        .label("Secret")
-        .legend(|(x, y)| Circle::new((x, y), 5, GREEN.filled()));
-
-    Ok(())
+    sink.write(path, render_svg(dimensions, draw)?.as_bytes())
 }
 
 /// Creates a chart with a simple line.
 ///
 /// The chosen polynomial is x.
-fn line() -> Result<(), Box<dyn Error>> {
-    let filename = Path::new("plots").join("line.svg");
-
-    create_chart(
-        &filename,
-        "2 Points are Uniquely Determined by a Line",
-        DIMENSIONS,
-        2.5f32..4.5f32,
-        2.0f32..4.5f32,
-        identity,
-        "x",
-        &[3.0, 4.0],
-        false,
-    )?;
+fn line(sink: &mut dyn PlotSink, mode: Mode) -> Result<(), Error> {
+    let filename = directory(mode).join("line.svg");
+
+    let svg = render_to_string(&ChartSpec {
+        title: "2 Points are Uniquely Determined by a Line".to_string(),
+        dimensions: mode.dimensions(),
+        x_range: 2.5f32..4.5f32,
+        y_range: 2.0f32..4.5f32,
+        polynomial: Polynomial::new(vec![0.0, 1.0]),
+        polynomial_str: "x".to_string(),
+        shares_x: vec![3.0, 4.0],
+        secret: false,
+        inset: None,
+        mode,
+    })?;
+    sink.write(&filename, svg.as_bytes())?;
 
     Ok(())
 }
@@ -187,20 +65,22 @@ fn line() -> Result<(), Box<dyn Error>> {
 /// Creates a chart with a quadratic polynomial.
 ///
 /// The chosen polynomial is x².
-fn quadratic() -> Result<(), Box<dyn Error>> {
-    let filename = Path::new("plots").join("quadratic.svg");
-
-    create_chart(
-        &filename,
-        "3 Points are Uniquely Determined by a Parabola",
-        DIMENSIONS,
-        -5.1f32..5.1f32,
-        -1f32..26f32,
-        |x| x.powi(2),
-        "x²",
-        &[-4.0, 1.0, 4.0],
-        false,
-    )?;
+fn quadratic(sink: &mut dyn PlotSink, mode: Mode) -> Result<(), Error> {
+    let filename = directory(mode).join("quadratic.svg");
+
+    let svg = render_to_string(&ChartSpec {
+        title: "3 Points are Uniquely Determined by a Parabola".to_string(),
+        dimensions: mode.dimensions(),
+        x_range: -5.1f32..5.1f32,
+        y_range: -1f32..26f32,
+        polynomial: Polynomial::new(vec![0.0, 0.0, 1.0]),
+        polynomial_str: "x²".to_string(),
+        shares_x: vec![-4.0, 1.0, 4.0],
+        secret: false,
+        inset: None,
+        mode,
+    })?;
+    sink.write(&filename, svg.as_bytes())?;
 
     Ok(())
 }
@@ -208,20 +88,22 @@ fn quadratic() -> Result<(), Box<dyn Error>> {
 /// Creates a chart with a cubic polynomial.
 ///
 /// The chosen polynomial is x³.
-fn cubic() -> Result<(), Box<dyn Error>> {
-    let filename = Path::new("plots").join("cubic.svg");
-
-    create_chart(
-        &filename,
-        "4 Points are Uniquely Determined by a Cubic",
-        DIMENSIONS,
-        -2.5f32..2.5f32,
-        -20.0f32..20.0f32,
-        |x| x.powi(3),
-        "x³",
-        &[-2.0, -1.0, 1.0, 2.0],
-        false,
-    )?;
+fn cubic(sink: &mut dyn PlotSink, mode: Mode) -> Result<(), Error> {
+    let filename = directory(mode).join("cubic.svg");
+
+    let svg = render_to_string(&ChartSpec {
+        title: "4 Points are Uniquely Determined by a Cubic".to_string(),
+        dimensions: mode.dimensions(),
+        x_range: -2.5f32..2.5f32,
+        y_range: -20.0f32..20.0f32,
+        polynomial: Polynomial::new(vec![0.0, 0.0, 0.0, 1.0]),
+        polynomial_str: "x³".to_string(),
+        shares_x: vec![-2.0, -1.0, 1.0, 2.0],
+        secret: false,
+        inset: None,
+        mode,
+    })?;
+    sink.write(&filename, svg.as_bytes())?;
 
     Ok(())
 }
@@ -229,20 +111,22 @@ fn cubic() -> Result<(), Box<dyn Error>> {
 /// Creates a chart with a polynomial, its shares and the secret.
 ///
 /// The chosen polynomial is 2x³ - 3x² + 2x + 5.
-fn shamir() -> Result<(), Box<dyn Error>> {
-    let filename = Path::new("plots").join("shamir.svg");
-
-    create_chart(
-        &filename,
-        "Shamir's Secret Sharing",
-        DIMENSIONS,
-        -2.1f32..2.4f32,
-        -30.0f32..20.0f32,
-        |x| 2.0 * x.powi(3) - 3.0 * x.powi(2) + 2.0 * x + 5.0,
-        "2x³ - 3x² + 2x + 5",
-        &[-2.0, -1.0, 0.5, 1.0, 2.0],
-        true,
-    )?;
+fn shamir(sink: &mut dyn PlotSink, mode: Mode) -> Result<(), Error> {
+    let filename = directory(mode).join("shamir.svg");
+
+    let svg = render_to_string(&ChartSpec {
+        title: "Shamir's Secret Sharing".to_string(),
+        dimensions: mode.dimensions(),
+        x_range: -2.1f32..2.4f32,
+        y_range: -30.0f32..20.0f32,
+        polynomial: Polynomial::new(vec![5.0, 2.0, -3.0, 2.0]),
+        polynomial_str: "2x³ - 3x² + 2x + 5".to_string(),
+        shares_x: vec![-2.0, -1.0, 0.5, 1.0, 2.0],
+        secret: true,
+        inset: None,
+        mode,
+    })?;
+    sink.write(&filename, svg.as_bytes())?;
 
     Ok(())
 }
@@ -251,20 +135,22 @@ fn shamir() -> Result<(), Box<dyn Error>> {
 /// an alternate single share and the secret.
 ///
 /// The chosen polynomial is 2x³ - 3x² + 2x + 5.
-fn shamir_alternate_single() -> Result<(), Box<dyn Error>> {
-    let filename = Path::new("plots").join("shamir_alternate_single.svg");
-
-    create_chart(
-        &filename,
-        "Shamir's Secret Sharing: Alternate Single Share",
-        DIMENSIONS,
-        -1.1f32..3.4f32,
-        -30.0f32..60.0f32,
-        |x| 2.0 * x.powi(3) - 3.0 * x.powi(2) + 2.0 * x + 5.0,
-        "2x³ - 3x² + 2x + 5",
-        &[-1.0, 0.5, 1.0, 2.0, 3.0],
-        true,
-    )?;
+fn shamir_alternate_single(sink: &mut dyn PlotSink, mode: Mode) -> Result<(), Error> {
+    let filename = directory(mode).join("shamir_alternate_single.svg");
+
+    let svg = render_to_string(&ChartSpec {
+        title: "Shamir's Secret Sharing: Alternate Single Share".to_string(),
+        dimensions: mode.dimensions(),
+        x_range: -1.1f32..3.4f32,
+        y_range: -30.0f32..60.0f32,
+        polynomial: Polynomial::new(vec![5.0, 2.0, -3.0, 2.0]),
+        polynomial_str: "2x³ - 3x² + 2x + 5".to_string(),
+        shares_x: vec![-1.0, 0.5, 1.0, 2.0, 3.0],
+        secret: true,
+        inset: None,
+        mode,
+    })?;
+    sink.write(&filename, svg.as_bytes())?;
 
     Ok(())
 }
@@ -273,35 +159,336 @@ fn shamir_alternate_single() -> Result<(), Box<dyn Error>> {
 /// alternate multiple shares and the secret.
 ///
 /// The chosen polynomial is 2x³ - 3x² + 2x + 5.
-fn shamir_alternate_multiple() -> Result<(), Box<dyn Error>> {
-    let filename = Path::new("plots").join("shamir_alternate_multiple.svg");
-
-    create_chart(
-        &filename,
-        "Shamir's Secret Sharing: Alternate Multiple Shares",
-        DIMENSIONS,
-        -2.7f32..3.0f32,
-        -70.0f32..60.0f32,
-        |x| 2.0 * x.powi(3) - 3.0 * x.powi(2) + 2.0 * x + 5.0,
-        "2x³ - 3x² + 2x + 5",
-        &[-2.5, -1.5, 0.8, 1.5, 2.5],
-        true,
-    )?;
+fn shamir_alternate_multiple(sink: &mut dyn PlotSink, mode: Mode) -> Result<(), Error> {
+    let filename = directory(mode).join("shamir_alternate_multiple.svg");
+
+    let svg = render_to_string(&ChartSpec {
+        title: "Shamir's Secret Sharing: Alternate Multiple Shares".to_string(),
+        dimensions: mode.dimensions(),
+        x_range: -2.7f32..3.0f32,
+        y_range: -70.0f32..60.0f32,
+        polynomial: Polynomial::new(vec![5.0, 2.0, -3.0, 2.0]),
+        polynomial_str: "2x³ - 3x² + 2x + 5".to_string(),
+        shares_x: vec![-2.5, -1.5, 0.8, 1.5, 2.5],
+        secret: true,
+        inset: Some((0.25, 1.5)),
+        mode,
+    })?;
+    sink.write(&filename, svg.as_bytes())?;
+
+    Ok(())
+}
+
+/// Creates a chart with a polynomial and its reconstruction
+/// from a set of shares where one share has been corrupted.
+///
+/// The chosen polynomial is 2x³ - 3x² + 2x + 5.
+/// The share at x = 1 is shifted by 3, and the reconstructed
+/// polynomial is obtained with Lagrange interpolation
+/// through the shares, yielding a wrong secret when combined.
+fn shamir_corrupted_share(sink: &mut dyn PlotSink, mode: Mode) -> Result<(), Error> {
+    let filename = directory(mode).join("shamir_corrupted_share.svg");
+    let x_range = -1.3f32..2.4f32;
+    let y_range = -10.0f32..22.0f32;
+
+    let polynomial = Polynomial::new(vec![5.0, 2.0, -3.0, 2.0]);
+    let honest_shares = shares_of(&polynomial, &[-1.0, 0.5, 2.0]);
+    let true_shares = shares_of(&polynomial, &[-1.0, 0.5, 2.0, 1.0]);
+    let corrupted_share = Share::new(1.0, polynomial.evaluate(1.0) + 3.0, 4, 4);
+    let mut shares = honest_shares.clone();
+    shares.push(corrupted_share.clone());
+    let reconstruction = interpolate(&shares)?;
+
+    render(sink, &filename, mode.dimensions(), |root_area| {
+        root_area.fill(&TRANSPARENT)?;
+
+        let chart_area = draw_caption(
+            &root_area.margin(5, 5, 5, 5),
+            "Shamir's Secret Sharing: Corrupted Share",
+            mode,
+        )?;
+        let chart_area = draw_coefficients(&chart_area, &polynomial, mode)?;
+
+        let mut chart = ChartBuilder::on(&chart_area)
+            .x_label_area_size(35)
+            .y_label_area_size(40)
+            .build_cartesian_2d(x_range.clone(), y_range.clone())?;
+
+        draw_axes(&mut chart, shares.len() + 1, mode)?;
+
+        // add the true and the reconstructed polynomials, the shares and both secrets
+        draw_polynomial(
+            &mut chart,
+            &polynomial,
+            "2x³ - 3x² + 2x + 5",
+            x_range.clone(),
+            BLUE,
+            mode,
+        )?;
+        draw_polynomial(
+            &mut chart,
+            &reconstruction,
+            "Reconstruction",
+            x_range,
+            MAGENTA,
+            mode,
+        )?;
+        draw_shares(&mut chart, &honest_shares, mode)?;
+        draw_corrupted_share(&mut chart, &polynomial, &corrupted_share, mode)?;
+        draw_secret(&mut chart, &true_shares, "Secret", GREEN, mode)?;
+        draw_secret(&mut chart, &shares, "Wrong secret", MAGENTA, mode)?;
+
+        draw_legend(&mut chart, SeriesLabelPosition::UpperLeft, mode)?;
+
+        Ok(())
+    })
+}
+
+/// Creates a composite chart of the same polynomial and shares
+/// over the real numbers, at the left, and over the prime field GF(31), at the right.
+///
+/// The chosen polynomial is 2x³ - 3x² + 2x + 5, hence 4 shares are needed,
+/// and the shares are at x = 1, 2, 3, 4.
+/// Over the reals, the shares lie on a smooth curve;
+/// over GF(31), the polynomial is only a scatter of 31 points,
+/// from which the shares reconstruct the same secret.
+fn shamir_finite_field(sink: &mut dyn PlotSink, mode: Mode) -> Result<(), Error> {
+    let filename = directory(mode).join("shamir_finite_field.svg");
+    let (width, height) = mode.dimensions();
+    let coefficients = [5.0, 2.0, -3.0, 2.0];
+    let shares_x = [1.0, 2.0, 3.0, 4.0];
+
+    let polynomial = Polynomial::new(coefficients.to_vec());
+    let shares = shares_of(&polynomial, &shares_x);
+
+    let field_polynomial = shamir_secret_sharing::polynomial::Polynomial::new(
+        coefficients.iter().map(|&c| to_field(c)).collect(),
+    );
+    let field_shares: Vec<shamir_secret_sharing::Share<Fp31>> = shares_x
+        .iter()
+        .map(|&x| {
+            let x = Fp31::new(x as u64);
+            let y = field_polynomial.evaluate(&x);
+            shamir_secret_sharing::Share::new(x, vec![y], shares_x.len(), shares_x.len())
+        })
+        .collect();
+    let field_secret = interpolate_at(&field_shares, &Fp31::new(0))?[0];
+
+    render(sink, &filename, (2 * width, height), |root_area| {
+        root_area.fill(&TRANSPARENT)?;
+
+        let chart_area = draw_caption(
+            &root_area.margin(5, 5, 5, 5),
+            "Shamir's Secret Sharing: Real Numbers and GF(31)",
+            mode,
+        )?;
+        let chart_area = draw_coefficients(&chart_area, &polynomial, mode)?;
+        let (real_area, field_area) =
+            chart_area.split_horizontally(chart_area.dim_in_pixel().0 / 2);
+        let subcaption_style = ("sans-serif", mode.caption_size() * 2 / 3);
+
+        // the real numbers
+        let x_range = -0.5f32..4.3f32;
+        let y_range = -10.0f32..120.0f32;
+        let mut chart = ChartBuilder::on(&real_area)
+            .caption("Over the real numbers", subcaption_style)
+            .x_label_area_size(35)
+            .y_label_area_size(40)
+            .build_cartesian_2d(x_range.clone(), y_range)?;
+
+        draw_axes(&mut chart, shares_x.len() + 1, mode)?;
+
+        draw_polynomial(
+            &mut chart,
+            &polynomial,
+            "2x³ - 3x² + 2x + 5",
+            x_range,
+            BLUE,
+            mode,
+        )?;
+        draw_shares(&mut chart, &shares, mode)?;
+        draw_secret(&mut chart, &shares, "Secret", GREEN, mode)?;
+
+        draw_legend(&mut chart, SeriesLabelPosition::UpperLeft, mode)?;
+
+        // the prime field, whose elements are drawn as their representatives in [0, 31)
+        let range = -1.0f32..Fp31::MAX_SHARES as f32 + 1.0;
+        let mut chart = ChartBuilder::on(&field_area)
+            .caption("Over GF(31)", subcaption_style)
+            .x_label_area_size(35)
+            .y_label_area_size(40)
+            .build_cartesian_2d(range.clone(), range)?;
+
+        draw_axes(&mut chart, 5, mode)?;
+
+        let points: Vec<(f32, f32)> = (0..=Fp31::MAX_SHARES)
+            .map(|x| {
+                let y = field_polynomial.evaluate(&Fp31::new(x));
+                (x as f32, y.value() as f32)
+            })
+            .collect();
+        chart
+            .draw_series(
+                points
+                    .iter()
+                    .map(|&point| Circle::new(point, 3, BLUE.mix(0.5).filled())),
+            )?
+            .label("Points of the polynomial")
+            .legend(|(x, y)| Circle::new((x, y), 3, BLUE.mix(0.5).filled()));
+
+        let share_points: Vec<Share> = field_shares
+            .iter()
+            .map(|share| {
+                let (x, y) = (share.x().value(), share.y()[0].value());
+                Share::new(x as f64, y as f64, share.threshold(), share.total())
+            })
+            .collect();
+        draw_shares(&mut chart, &share_points, mode)?;
+        draw_points(
+            &mut chart,
+            vec![(0.0, field_secret.value() as f32)],
+            GREEN.filled(),
+            mode,
+        )?
+        .label("Secret")
+        .legend(|(x, y)| Circle::new((x, y), 5, GREEN.filled()));
+
+        draw_legend(&mut chart, SeriesLabelPosition::UpperRight, mode)?;
+
+        Ok(())
+    })
+}
+
+/// Maps an integer coefficient into GF(31), negative coefficients to their opposites.
+fn to_field(coefficient: f64) -> Fp31 {
+    let magnitude = Fp31::new(coefficient.abs() as u64);
+    if coefficient < 0.0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Creates an animation of a polynomial whose shares are removed one by one.
+/// The animation is saved to a GIF file, one frame per number of remaining shares,
+/// rather than to a sink, as the GIF backend only writes files.
+///
+/// The chosen polynomial is 2x³ - 3x² + 2x + 5, hence 4 shares are needed.
+/// Every frame interpolates the remaining shares:
+/// while at least 4 shares remain, the polynomial and the secret
+/// are uniquely determined; once fewer remain, the shares are equally
+/// consistent with candidate polynomials through any other secret,
+/// shaded as the band they sweep for secrets between -15 and 15.
+fn shamir_share_removal() -> Result<(), Error> {
+    let mode = Mode::Full;
+    let filename = directory(mode).join("shamir_share_removal.gif");
+    let x_range = -2.1f32..2.4f32;
+    let y_range = -30.0f32..20.0f32;
+
+    let polynomial = Polynomial::new(vec![5.0, 2.0, -3.0, 2.0]);
+    let threshold = polynomial.coefficients().len();
+    let shares_x = [-2.0, -1.0, 0.5, 1.0, 2.0];
+    let candidate_secrets = [-15.0, -5.0, 5.0, 15.0];
+    let sampled_secrets = (-30..=30).map(|secret| f64::from(secret) / 2.0);
+
+    let root_area = BitMapBackend::gif(&filename, mode.dimensions(), 1_500)
+        .map_err(|error| Error::Plotting(Box::new(error)))?
+        .into_drawing_area();
+
+    for remaining in (1..=shares_x.len()).rev() {
+        root_area.fill(&WHITE)?;
+
+        let remaining_x = &shares_x[..remaining];
+        let shares = shares_of(&polynomial, remaining_x);
+
+        let title = format!(
+            "Shamir's Secret Sharing: {remaining} of {} Shares",
+            shares_x.len()
+        );
+        let chart_area = draw_caption(&root_area.margin(5, 5, 5, 5), &title, mode)?;
+        let step = if remaining == shares_x.len() {
+            format!("All {remaining} shares lie on a single curve")
+        } else if remaining >= threshold {
+            format!(
+                "Share {} removed — {remaining} shares still determine the curve",
+                remaining + 1
+            )
+        } else {
+            format!(
+                "Share {} removed — curve ambiguous, every secret possible",
+                remaining + 1
+            )
+        };
+        let chart_area = draw_step_caption(&chart_area, &step, mode)?;
+
+        let mut chart = ChartBuilder::on(&chart_area)
+            .x_label_area_size(35)
+            .y_label_area_size(40)
+            .build_cartesian_2d(x_range.clone(), y_range.clone())?;
+
+        draw_axes(&mut chart, shares_x.len() + 1, mode)?;
+
+        if remaining >= threshold {
+            // the remaining shares determine a unique polynomial and secret
+            let reconstruction = interpolate(&shares)?;
+            draw_polynomial(
+                &mut chart,
+                &reconstruction,
+                "Reconstruction",
+                x_range.clone(),
+                BLUE,
+                mode,
+            )?;
+            draw_shares(&mut chart, &shares, mode)?;
+            draw_secret(&mut chart, &shares, "Secret", GREEN, mode)?;
+        } else {
+            // every secret is consistent with the remaining shares
+            let candidates = sampled_secrets
+                .clone()
+                .map(|secret| {
+                    let mut points: Vec<(f64, f64)> =
+                        shares.iter().map(|share| (share.x(), share.y())).collect();
+                    points.push((0.0, secret));
+                    Polynomial::interpolate(&points)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            draw_band(&mut chart, &candidates, x_range.clone(), y_range.clone())?;
+            draw_shares(&mut chart, &shares, mode)?;
+            let secrets: Vec<(f32, f32)> =
+                candidate_secrets.iter().map(|&y| (0.0, y as f32)).collect();
+            draw_points(&mut chart, secrets, GREEN.mix(0.5).filled(), mode)?
+                .label("Candidate secrets")
+                .legend(|(x, y)| Circle::new((x, y), 5, GREEN.mix(0.5).filled()));
+        }
+
+        draw_legend(&mut chart, SeriesLabelPosition::LowerRight, mode)?;
+
+        root_area.present()?;
+    }
 
     Ok(())
 }
 
 /// The main function.
-/// Calls the functions to create the charts.
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Guarantee that the plots directory exists
-    create_dir_all("plots")?;
-    line()?;
-    quadratic()?;
-    cubic()?;
-    shamir()?;
-    shamir_alternate_single()?;
-    shamir_alternate_multiple()?;
+/// Calls the functions to create the charts,
+/// in full detail, as compact thumbnails and annotated with their coefficients.
+fn main() -> Result<(), Error> {
+    // the meshes and legends are drawn with plotters directly, with the bundled font
+    register_font();
+    let mut sink = FileSink;
+    for mode in [Mode::Full, Mode::Compact, Mode::Annotated] {
+        // Guarantee that the plots directory exists
+        create_dir_all(directory(mode))?;
+        line(&mut sink, mode)?;
+        quadratic(&mut sink, mode)?;
+        cubic(&mut sink, mode)?;
+        shamir(&mut sink, mode)?;
+        shamir_alternate_single(&mut sink, mode)?;
+        shamir_alternate_multiple(&mut sink, mode)?;
+        shamir_corrupted_share(&mut sink, mode)?;
+        shamir_finite_field(&mut sink, mode)?;
+    }
+    shamir_share_removal()?;
 
     Ok(())
 }
@@ -0,0 +1,68 @@
+//! Padding of secrets, to hide their length.
+//!
+//! Every share holds one y-coordinate for every byte of the secret,
+//! so that the length of a share is the length of the secret.
+//! Padding the secret to a multiple of a block size before splitting it
+//! only reveals the number of blocks.
+//!
+//! The padding is the one of ISO/IEC 7816-4: a `0x80` byte followed by zeros,
+//! up to the next multiple of the block size,
+//! hence it is always present and can be removed unambiguously.
+
+use crate::error::Error;
+use crate::validation::validate_block_size;
+
+/// The first byte of the padding, followed by zeros.
+const MARKER: u8 = 0x80;
+
+/// Pads a secret to the next multiple of `block_size` bytes, with at least one byte of padding.
+///
+/// ## Errors
+///
+/// Returns an error if `block_size` is zero.
+pub fn pad(secret: &[u8], block_size: usize) -> Result<Vec<u8>, Error> {
+    validate_block_size(block_size)?;
+    let length = (secret.len() / block_size + 1) * block_size;
+    let mut padded = Vec::with_capacity(length);
+    padded.extend_from_slice(secret);
+    padded.push(MARKER);
+    padded.resize(length, 0);
+    Ok(padded)
+}
+
+/// Removes the padding of a secret.
+///
+/// ## Errors
+///
+/// Returns an error if the secret does not end with a padding,
+/// for example if it was not padded or if the shares are incorrect.
+pub fn unpad(padded: &[u8]) -> Result<&[u8], Error> {
+    match padded.iter().rposition(|&byte| byte != 0) {
+        Some(end) if padded[end] == MARKER => Ok(&padded[..end]),
+        _ => Err(Error::InvalidPadding),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_to_the_next_block() {
+        assert_eq!(pad(b"abc", 4).unwrap(), b"abc\x80");
+        assert_eq!(pad(b"abcd", 4).unwrap(), b"abcd\x80\0\0\0");
+        assert_eq!(unpad(&pad(b"abcd", 4).unwrap()).unwrap(), b"abcd");
+        assert_eq!(unpad(&pad(b"a\x80\0", 16).unwrap()).unwrap(), b"a\x80\0");
+    }
+
+    #[test]
+    fn rejects_a_zero_block_size() {
+        assert!(matches!(pad(b"abc", 0), Err(Error::ZeroBlockSize)));
+    }
+
+    #[test]
+    fn rejects_unpadded_secrets() {
+        assert!(matches!(unpad(b"abc"), Err(Error::InvalidPadding)));
+        assert!(matches!(unpad(b"\0\0"), Err(Error::InvalidPadding)));
+    }
+}
@@ -0,0 +1,208 @@
+//! Pedersen's verifiable secret sharing.
+//!
+//! Feldman's commitments, see the [`crate::feldman`] module, reveal sG for the secret s.
+//! Pedersen's scheme blinds them with a second random polynomial b:
+//! the dealer publishes Cⱼ = aⱼG + bⱼH for every coefficient aⱼ of the polynomial,
+//! where H is a second generator whose discrete logarithm to the base G is unknown.
+//! Every share holds the value of both polynomials at its x-coordinate,
+//! and the holder of the share (x, y, r) verifies it by checking
+//! that yG + rH is the sum of xʲCⱼ.
+//!
+//! As the blinding hides every coefficient, including the secret,
+//! the commitments reveal nothing about the secret, even to an unbounded adversary,
+//! while the dealer cannot open them to another polynomial unless it breaks discrete logarithms.
+//!
+//! This module requires the `vss` feature.
+
+use std::sync::OnceLock;
+
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::RistrettoPoint;
+use rand::{CryptoRng, RngCore};
+use sha2::Sha512;
+
+use crate::error::Error;
+use crate::feldman::{self, Commitments};
+use crate::field::Field;
+use crate::polynomial::Polynomial;
+use crate::ristretto::RistrettoScalar;
+use crate::validation::validate_parameters;
+use crate::Share;
+
+/// The input hashed to the second generator, in which nothing is hidden.
+const GENERATOR_DOMAIN: &[u8] = b"shamir-secret-sharing Pedersen generator H";
+
+/// Returns the second generator H, hashed to the Ristretto group,
+/// hence of unknown discrete logarithm to the base G.
+pub fn blinding_generator() -> &'static RistrettoPoint {
+    static GENERATOR: OnceLock<RistrettoPoint> = OnceLock::new();
+    GENERATOR.get_or_init(|| RistrettoPoint::hash_from_bytes::<Sha512>(GENERATOR_DOMAIN))
+}
+
+/// A share of a secret, along with the value of the blinding polynomial
+/// at its x-coordinate.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PedersenShare {
+    share: Share<RistrettoScalar>,
+    blinding: RistrettoScalar,
+}
+
+impl PedersenShare {
+    /// Creates a share from its parts, such as the ones received from the dealer.
+    ///
+    /// ## Arguments
+    ///
+    /// * `share` - The share of the secret, with a single y-coordinate.
+    /// * `blinding` - The value of the blinding polynomial at the x-coordinate of the share.
+    pub fn new(share: Share<RistrettoScalar>, blinding: RistrettoScalar) -> Self {
+        PedersenShare { share, blinding }
+    }
+
+    /// Returns the share of the secret.
+    pub fn share(&self) -> &Share<RistrettoScalar> {
+        &self.share
+    }
+
+    /// Returns the value of the blinding polynomial at the x-coordinate of the share.
+    pub fn blinding(&self) -> &RistrettoScalar {
+        &self.blinding
+    }
+}
+
+/// Splits a secret into `n` shares, any `k` of which reconstruct it,
+/// along with the blinded commitments to the polynomial.
+///
+/// The secret is the constant term of a single polynomial over the Ristretto scalar field,
+/// blinded by another random polynomial of the same degree.
+///
+/// ## Errors
+///
+/// Returns an error if `k` is zero or greater than `n`.
+pub fn split(
+    secret: &RistrettoScalar,
+    k: usize,
+    n: usize,
+) -> Result<(Vec<PedersenShare>, Commitments), Error> {
+    split_with_rng(secret, k, n, &mut rand::thread_rng())
+}
+
+/// Splits a secret into `n` shares, any `k` of which reconstruct it,
+/// along with the blinded commitments to the polynomial,
+/// drawing the coefficients of both polynomials from the given random number generator.
+///
+/// See [`split`] for the details and errors.
+pub fn split_with_rng<R: CryptoRng + RngCore + ?Sized>(
+    secret: &RistrettoScalar,
+    k: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<(Vec<PedersenShare>, Commitments), Error> {
+    validate_parameters::<RistrettoScalar>(k, n)?;
+
+    let polynomial = Polynomial::random(*secret, k - 1, rng);
+    let blinding = Polynomial::random(RistrettoScalar::random(rng), k - 1, rng);
+    let shares = (1..=n as u64)
+        .map(|i| {
+            let x = RistrettoScalar::from_u64(i);
+            PedersenShare::new(
+                Share::new(x, vec![polynomial.evaluate(&x)], k, n),
+                blinding.evaluate(&x),
+            )
+        })
+        .collect();
+
+    // the trailing zeros of either polynomial are trimmed, the other one may be longer
+    let coefficient = |polynomial: &Polynomial<RistrettoScalar>, j: usize| {
+        polynomial
+            .coefficients()
+            .get(j)
+            .map_or(RistrettoScalar::zero(), |coefficient| *coefficient)
+    };
+    let length = polynomial
+        .coefficients()
+        .len()
+        .max(blinding.coefficients().len());
+    let commitments = (0..length)
+        .map(|j| {
+            RISTRETTO_BASEPOINT_POINT * coefficient(&polynomial, j).0
+                + blinding_generator() * coefficient(&blinding, j).0
+        })
+        .collect();
+
+    Ok((shares, Commitments::from_points(commitments)))
+}
+
+/// Verifies a share against the blinded commitments of its split.
+///
+/// Returns whether the share and its blinding lie on the committed polynomials,
+/// which is false for a share without exactly one y-coordinate,
+/// or if the polynomials have more coefficients than the threshold of the share,
+/// as in [`crate::feldman::verify`].
+pub fn verify(share: &PedersenShare, commitments: &Commitments) -> bool {
+    if commitments.points().len() > share.share.threshold() {
+        return false;
+    }
+    match share.share.y() {
+        [y] => {
+            RISTRETTO_BASEPOINT_POINT * y.0 + blinding_generator() * share.blinding.0
+                == commitments.evaluate(share.share.x())
+        }
+        _ => false,
+    }
+}
+
+/// Combines shares to reconstruct the secret, ignoring their blindings.
+///
+/// ## Errors
+///
+/// See [`feldman::combine`].
+pub fn combine(shares: &[PedersenShare]) -> Result<RistrettoScalar, Error> {
+    let shares: Vec<Share<RistrettoScalar>> =
+        shares.iter().map(|share| share.share.clone()).collect();
+    feldman::combine(&shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_verifies_every_share() {
+        let secret = RistrettoScalar::from_u64(42);
+        let (shares, commitments) = split(&secret, 3, 5).unwrap();
+        assert!(shares.iter().all(|share| verify(share, &commitments)));
+        assert_eq!(combine(&shares[..3]).unwrap(), secret);
+    }
+
+    #[test]
+    fn rejects_tampered_shares_and_blindings() {
+        let (shares, commitments) = split(&RistrettoScalar::from_u64(42), 3, 5).unwrap();
+        let mut tampered = shares[0].clone();
+        tampered.blinding = tampered.blinding + RistrettoScalar::one();
+        assert!(!verify(&tampered, &commitments));
+        let mut tampered = shares[0].clone();
+        let y = tampered.share.y()[0] + RistrettoScalar::one();
+        tampered.share = Share::new(*tampered.share.x(), vec![y], 3, 5);
+        assert!(!verify(&tampered, &commitments));
+    }
+
+    #[test]
+    fn verifies_shares_rebuilt_from_their_parts() {
+        let (shares, commitments) = split(&RistrettoScalar::from_u64(42), 3, 5).unwrap();
+        let commitments = Commitments::from_points(commitments.points().to_vec());
+        for share in &shares {
+            let rebuilt = PedersenShare::new(share.share().clone(), *share.blinding());
+            assert_eq!(&rebuilt, share);
+            assert!(verify(&rebuilt, &commitments));
+        }
+    }
+
+    #[test]
+    fn rejects_commitments_above_the_threshold_degree() {
+        let (shares, commitments) = split(&RistrettoScalar::from_u64(42), 4, 5).unwrap();
+        for mut share in shares {
+            share.share = Share::new(*share.share.x(), share.share.y().to_vec(), 3, 5);
+            assert!(!verify(&share, &commitments));
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Polynomials over a field.
+//!
+//! A [`Polynomial`] hides every element of a secret in its constant term,
+//! and the shares are its values at non-zero x-coordinates.
+
+use std::ops::{Add, Mul};
+
+use rand::Rng;
+
+use crate::error::Error;
+use crate::field::Field;
+
+/// A polynomial over the field `F`.
+///
+/// The coefficients are stored from the constant term up,
+/// without trailing zeros, so that the zero polynomial has no coefficients.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Polynomial<F> {
+    coefficients: Vec<F>,
+}
+
+impl<F: Field> Polynomial<F> {
+    /// Creates a polynomial from its coefficients, from the constant term up.
+    pub fn new(mut coefficients: Vec<F>) -> Self {
+        while coefficients.last() == Some(&F::zero()) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+
+    /// Creates a polynomial with the given constant term,
+    /// whose `degree` other coefficients are drawn uniformly from the field.
+    ///
+    /// The leading coefficient may be zero, hence the degree of the polynomial
+    /// is at most, rather than exactly, `degree`.
+    pub fn random<R: Rng + ?Sized>(constant: F, degree: usize, rng: &mut R) -> Self {
+        Polynomial::new(
+            std::iter::once(constant)
+                .chain((0..degree).map(|_| F::random(rng)))
+                .collect(),
+        )
+    }
+
+    /// Returns the Lagrange interpolating polynomial through the given points,
+    /// of degree lower than the number of points.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if two points have the same x-coordinate.
+    pub fn interpolate(points: &[(F, F)]) -> Result<Self, Error> {
+        let mut polynomial = Polynomial::new(Vec::new());
+        for (j, (x_j, y_j)) in points.iter().enumerate() {
+            let mut basis = Polynomial::new(vec![F::one()]);
+            let mut denominator = F::one();
+            for (m, (x_m, _)) in points.iter().enumerate() {
+                if m != j {
+                    basis = basis * Polynomial::new(vec![-x_m.clone(), F::one()]);
+                    denominator = denominator * (x_j.clone() - x_m.clone());
+                }
+            }
+            let scale = y_j.clone() * denominator.inverse().ok_or(Error::DuplicateShares)?;
+            polynomial = polynomial + basis * scale;
+        }
+        Ok(polynomial)
+    }
+
+    /// Returns the coefficients of the polynomial, from the constant term up.
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// Returns the degree of the polynomial, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's method.
+    ///
+    /// The polynomial is rewritten as a₀ + x(a₁ + x(a₂ + ...)),
+    /// which takes a single multiplication and addition per coefficient,
+    /// instead of raising `x` to every power.
+    pub fn evaluate(&self, x: &F) -> F {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(F::zero(), |y, coefficient| {
+                y * x.clone() + coefficient.clone()
+            })
+    }
+}
+
+impl<F: Field> Add for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn add(self, rhs: Polynomial<F>) -> Polynomial<F> {
+        let (mut longer, shorter) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self.coefficients, rhs.coefficients)
+        } else {
+            (rhs.coefficients, self.coefficients)
+        };
+        for (coefficient, other) in longer.iter_mut().zip(shorter) {
+            *coefficient = coefficient.clone() + other;
+        }
+        Polynomial::new(longer)
+    }
+}
+
+impl<F: Field> Mul<F> for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    /// Multiplies the polynomial by a scalar.
+    fn mul(self, rhs: F) -> Polynomial<F> {
+        Polynomial::new(
+            self.coefficients
+                .into_iter()
+                .map(|coefficient| coefficient * rhs.clone())
+                .collect(),
+        )
+    }
+}
+
+impl<F: Field> Mul for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: Polynomial<F>) -> Polynomial<F> {
+        if self.coefficients.is_empty() || rhs.coefficients.is_empty() {
+            return Polynomial::new(Vec::new());
+        }
+        let mut coefficients =
+            vec![F::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] = coefficients[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prime::Fp257;
+
+    fn polynomial(coefficients: &[u64]) -> Polynomial<Fp257> {
+        Polynomial::new(coefficients.iter().map(|&c| Fp257::new(c)).collect())
+    }
+
+    #[test]
+    fn trims_trailing_zeros() {
+        assert_eq!(polynomial(&[1, 2, 0, 0]).coefficients().len(), 2);
+        assert_eq!(polynomial(&[0, 0]).degree(), None);
+        assert_eq!(polynomial(&[5]).degree(), Some(0));
+    }
+
+    #[test]
+    fn evaluates_with_horner() {
+        // 3 + 2x + x² at x = 10
+        assert_eq!(
+            polynomial(&[3, 2, 1]).evaluate(&Fp257::new(10)),
+            Fp257::new(123)
+        );
+        assert_eq!(polynomial(&[]).evaluate(&Fp257::new(10)), Fp257::zero());
+    }
+
+    #[test]
+    fn interpolates_through_the_points() {
+        let expected = polynomial(&[3, 2, 1]);
+        let points: Vec<(Fp257, Fp257)> = [1, 2, 3]
+            .map(|x| (Fp257::new(x), expected.evaluate(&Fp257::new(x))))
+            .to_vec();
+        assert_eq!(Polynomial::interpolate(&points).unwrap(), expected);
+    }
+
+    #[test]
+    fn rejects_duplicate_x_coordinates() {
+        let points = [
+            (Fp257::new(1), Fp257::new(2)),
+            (Fp257::new(1), Fp257::new(3)),
+        ];
+        assert!(matches!(
+            Polynomial::interpolate(&points),
+            Err(Error::DuplicateShares)
+        ));
+    }
+
+    #[test]
+    fn random_polynomials_keep_their_constant_term() {
+        let polynomial = Polynomial::random(Fp257::new(42), 4, &mut rand::thread_rng());
+        assert_eq!(polynomial.evaluate(&Fp257::zero()), Fp257::new(42));
+        assert!(polynomial.degree().unwrap() <= 4);
+    }
+}
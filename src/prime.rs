@@ -0,0 +1,164 @@
+//! Arithmetic over prime fields GF(p).
+//!
+//! Elements are integers modulo a prime `p` below 2⁶⁴,
+//! chosen at compile time through the `P` parameter of [`Fp`].
+//! Products are computed on 128 bits before being reduced.
+
+// Division multiplies by the inverse.
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use rand::Rng;
+
+use crate::field::Field;
+
+/// An element of the prime field GF(p), for a prime `P`.
+///
+/// `P` must be prime: inverses are computed with Fermat's little theorem,
+/// which yields wrong results for composite moduli.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fp<const P: u64>(u64);
+
+/// The prime field of order 257, the smallest prime field holding every byte.
+pub type Fp257 = Fp<257>;
+
+/// The prime field of order 2⁶¹ - 1, a Mersenne prime.
+pub type Mersenne61 = Fp<{ (1 << 61) - 1 }>;
+
+impl<const P: u64> Fp<P> {
+    /// Creates an element from an integer, reduced modulo `P`.
+    pub fn new(value: u64) -> Self {
+        Fp(value % P)
+    }
+
+    /// Returns the canonical representative of the element, in `[0, P)`.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+}
+
+impl<const P: u64> From<u64> for Fp<P> {
+    fn from(value: u64) -> Self {
+        Fp::new(value)
+    }
+}
+
+impl<const P: u64> fmt::Display for Fp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Add for Fp<P> {
+    type Output = Fp<P>;
+
+    fn add(self, rhs: Fp<P>) -> Fp<P> {
+        Fp(((u128::from(self.0) + u128::from(rhs.0)) % u128::from(P)) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Fp<P> {
+    type Output = Fp<P>;
+
+    fn sub(self, rhs: Fp<P>) -> Fp<P> {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Neg for Fp<P> {
+    type Output = Fp<P>;
+
+    fn neg(self) -> Fp<P> {
+        if self.0 == 0 {
+            self
+        } else {
+            Fp(P - self.0)
+        }
+    }
+}
+
+impl<const P: u64> Mul for Fp<P> {
+    type Output = Fp<P>;
+
+    fn mul(self, rhs: Fp<P>) -> Fp<P> {
+        Fp(((u128::from(self.0) * u128::from(rhs.0)) % u128::from(P)) as u64)
+    }
+}
+
+impl<const P: u64> Div for Fp<P> {
+    type Output = Fp<P>;
+
+    /// Divides two elements.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Fp<P>) -> Fp<P> {
+        self * rhs.inverse().expect("division by zero in GF(p)")
+    }
+}
+
+impl<const P: u64> Field for Fp<P> {
+    const MAX_SHARES: u64 = P - 1;
+
+    fn zero() -> Self {
+        Fp(0)
+    }
+
+    fn one() -> Self {
+        Fp(1 % P)
+    }
+
+    /// The multiplicative group has order `P - 1`, hence the inverse is a^(P - 2).
+    fn inverse(&self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Fp(rng.gen_range(0..P))
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Fp::new(value)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_non_zero_element_has_an_inverse() {
+        assert_eq!(Fp257::zero().inverse(), None);
+        for a in 1..257 {
+            let a = Fp257::new(a);
+            assert_eq!(a * a.inverse().unwrap(), Fp257::one(), "{a}");
+        }
+    }
+
+    #[test]
+    fn reduces_modulo_the_prime() {
+        assert_eq!(Fp257::new(257), Fp257::zero());
+        assert_eq!(Fp257::new(256) + Fp257::new(2), Fp257::new(1));
+        assert_eq!(Fp257::new(1) - Fp257::new(2), Fp257::new(256));
+        assert_eq!(-Fp257::new(1), Fp257::new(256));
+        assert_eq!(Fp257::new(16) * Fp257::new(16), Fp257::new(256));
+    }
+
+    #[test]
+    fn multiplies_without_overflow() {
+        let minus_one = Mersenne61::new((1 << 61) - 2);
+        assert_eq!(minus_one * minus_one, Mersenne61::one());
+        assert_eq!(Mersenne61::new(3) / Mersenne61::new(3), Mersenne61::one());
+    }
+}
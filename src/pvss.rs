@@ -0,0 +1,543 @@
+//! Schoenmakers' publicly verifiable secret sharing.
+//!
+//! In Feldman's and Pedersen's schemes, see the [`crate::feldman`] and [`crate::pedersen`] modules,
+//! only the holder of a share can verify it.
+//! Here every participant has a key pair (x, y = xG), and the dealer publishes
+//! the commitments Cⱼ = aⱼH to the coefficients of the polynomial p,
+//! along with every share p(i) encrypted to its participant as Yᵢ = p(i)yᵢ.
+//! A proof of equality of discrete logarithms, or DLEQ proof, shows that
+//! Yᵢ encrypts the share committed to by Xᵢ = p(i)H, the sum of iʲCⱼ,
+//! hence anyone verifies that the dealer behaved honestly, without learning any share.
+//!
+//! The participant decrypts its share into Sᵢ = x⁻¹Yᵢ = p(i)G,
+//! with a DLEQ proof that Sᵢ is the decryption of Yᵢ under its public key,
+//! and any `k` decrypted shares reconstruct the secret sG by Lagrange interpolation in the group.
+//! The secret is a point rather than a scalar, to derive a random value
+//! or a key encrypting the data to escrow.
+//!
+//! This module requires the `vss` feature.
+
+use std::fmt;
+
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::{RistrettoPoint, Scalar};
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+use crate::barycentric::Barycentric;
+use crate::error::Error;
+use crate::feldman::Commitments;
+use crate::field::Field;
+use crate::pedersen::blinding_generator;
+use crate::polynomial::Polynomial;
+use crate::ristretto::RistrettoScalar;
+use crate::validation::{validate_parameters, validate_x_coordinates};
+
+/// The input hashed first into the challenge of every DLEQ proof.
+const PROOF_DOMAIN: &[u8] = b"shamir-secret-sharing PVSS DLEQ proof";
+
+/// The key pair of a participant, a secret scalar x and its public point xG.
+///
+/// Its [`Debug`](fmt::Debug) output only shows the public key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct KeyPair {
+    secret: RistrettoScalar,
+    public: RistrettoPoint,
+}
+
+impl KeyPair {
+    /// Generates a key pair, with a secret key drawn from the given random number generator.
+    pub fn new<R: CryptoRng + RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut secret = RistrettoScalar::random(rng);
+        while secret == RistrettoScalar::zero() {
+            secret = RistrettoScalar::random(rng);
+        }
+        KeyPair {
+            secret,
+            public: RISTRETTO_BASEPOINT_POINT * secret.0,
+        }
+    }
+
+    /// Creates the key pair of a secret key, such as one stored by the participant.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the secret key is zero.
+    pub fn from_secret_key(secret: RistrettoScalar) -> Result<Self, Error> {
+        if secret == RistrettoScalar::zero() {
+            return Err(Error::ZeroSecretKey);
+        }
+
+        Ok(KeyPair {
+            secret,
+            public: RISTRETTO_BASEPOINT_POINT * secret.0,
+        })
+    }
+
+    /// Returns the secret key, to store the key pair.
+    pub fn secret_key(&self) -> &RistrettoScalar {
+        &self.secret
+    }
+
+    /// Returns the public key, to encrypt shares to the participant.
+    pub fn public_key(&self) -> &RistrettoPoint {
+        &self.public
+    }
+}
+
+impl fmt::Debug for KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyPair")
+            .field("public", &self.public)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A non-interactive proof that two points have the same discrete logarithm
+/// to their respective bases, made with the Fiat-Shamir heuristic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DleqProof {
+    challenge: Scalar,
+    response: Scalar,
+}
+
+impl DleqProof {
+    /// Proves that `α g₁` and `α g₂` have the same discrete logarithm `α`.
+    fn new<R: CryptoRng + RngCore + ?Sized>(
+        alpha: &Scalar,
+        (g1, g2): (&RistrettoPoint, &RistrettoPoint),
+        rng: &mut R,
+    ) -> Self {
+        let w = RistrettoScalar::random(rng).0;
+        let challenge = challenge(
+            [g1, &(g1 * alpha), g2, &(g2 * alpha)],
+            [&(g1 * w), &(g2 * w)],
+        );
+        DleqProof {
+            challenge,
+            response: w - alpha * challenge,
+        }
+    }
+
+    /// Creates a proof from its challenge and response, such as the ones of a published proof.
+    pub fn from_parts(challenge: Scalar, response: Scalar) -> Self {
+        DleqProof {
+            challenge,
+            response,
+        }
+    }
+
+    /// Returns the challenge of the proof.
+    pub fn challenge(&self) -> &Scalar {
+        &self.challenge
+    }
+
+    /// Returns the response of the proof.
+    pub fn response(&self) -> &Scalar {
+        &self.response
+    }
+
+    /// Verifies that `h₁` and `h₂` have the same discrete logarithm to the bases `g₁` and `g₂`.
+    fn verify(
+        &self,
+        (g1, h1): (&RistrettoPoint, &RistrettoPoint),
+        (g2, h2): (&RistrettoPoint, &RistrettoPoint),
+    ) -> bool {
+        let a1 = g1 * self.response + h1 * self.challenge;
+        let a2 = g2 * self.response + h2 * self.challenge;
+        challenge([g1, h1, g2, h2], [&a1, &a2]) == self.challenge
+    }
+}
+
+/// Hashes the statement and the commitments of a DLEQ proof into its challenge.
+fn challenge(statement: [&RistrettoPoint; 4], commitments: [&RistrettoPoint; 2]) -> Scalar {
+    let hash = statement
+        .into_iter()
+        .chain(commitments)
+        .fold(Sha512::new().chain_update(PROOF_DOMAIN), |hash, point| {
+            hash.chain_update(point.compress().as_bytes())
+        });
+    Scalar::from_hash(hash)
+}
+
+/// A share encrypted to the public key of a participant,
+/// with the proof that it is the share committed to by the dealer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedShare {
+    x: RistrettoScalar,
+    threshold: usize,
+    public_key: RistrettoPoint,
+    encrypted: RistrettoPoint,
+    proof: DleqProof,
+}
+
+impl EncryptedShare {
+    /// Creates an encrypted share from its parts, such as the ones published by the dealer.
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The x-coordinate of the share, never zero.
+    /// * `threshold` - The number of shares needed to reconstruct the secret.
+    /// * `public_key` - The public key the share is encrypted to.
+    /// * `encrypted` - The encrypted share p(x)y.
+    /// * `proof` - The proof that the share is the one committed to by the dealer.
+    pub fn new(
+        x: RistrettoScalar,
+        threshold: usize,
+        public_key: RistrettoPoint,
+        encrypted: RistrettoPoint,
+        proof: DleqProof,
+    ) -> Self {
+        EncryptedShare {
+            x,
+            threshold,
+            public_key,
+            encrypted,
+            proof,
+        }
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> &RistrettoScalar {
+        &self.x
+    }
+
+    /// Returns the number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the public key the share is encrypted to.
+    pub fn public_key(&self) -> &RistrettoPoint {
+        &self.public_key
+    }
+
+    /// Returns the encrypted share p(x)y.
+    pub fn encrypted(&self) -> &RistrettoPoint {
+        &self.encrypted
+    }
+
+    /// Returns the proof that the share is the one committed to by the dealer.
+    pub fn proof(&self) -> &DleqProof {
+        &self.proof
+    }
+}
+
+/// A share decrypted by its participant,
+/// with the proof that it is the decryption of its encrypted share.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecryptedShare {
+    x: RistrettoScalar,
+    threshold: usize,
+    decrypted: RistrettoPoint,
+    proof: DleqProof,
+}
+
+impl DecryptedShare {
+    /// Creates a decrypted share from its parts, such as the ones published by its participant.
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The x-coordinate of the share, never zero.
+    /// * `threshold` - The number of shares needed to reconstruct the secret.
+    /// * `decrypted` - The decrypted share p(x)G.
+    /// * `proof` - The proof that the share is the decryption of its encrypted share.
+    pub fn new(
+        x: RistrettoScalar,
+        threshold: usize,
+        decrypted: RistrettoPoint,
+        proof: DleqProof,
+    ) -> Self {
+        DecryptedShare {
+            x,
+            threshold,
+            decrypted,
+            proof,
+        }
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> &RistrettoScalar {
+        &self.x
+    }
+
+    /// Returns the number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the decrypted share p(x)G.
+    pub fn decrypted(&self) -> &RistrettoPoint {
+        &self.decrypted
+    }
+
+    /// Returns the proof that the share is the decryption of its encrypted share.
+    pub fn proof(&self) -> &DleqProof {
+        &self.proof
+    }
+}
+
+/// Splits a secret among the participants with the given public keys,
+/// any `k` of which reconstruct the point sG of the secret s,
+/// along with the commitments to the polynomial.
+///
+/// The participant with the i-th public key receives the share at x = i.
+///
+/// ## Errors
+///
+/// Returns an error if `k` is zero or greater than the number of public keys.
+pub fn split(
+    secret: &RistrettoScalar,
+    k: usize,
+    public_keys: &[RistrettoPoint],
+) -> Result<(Vec<EncryptedShare>, Commitments), Error> {
+    split_with_rng(secret, k, public_keys, &mut rand::thread_rng())
+}
+
+/// Splits a secret among the participants with the given public keys,
+/// along with the commitments to the polynomial,
+/// drawing the coefficients of the polynomial and the proofs from the given random number generator.
+///
+/// See [`split`] for the details and errors.
+pub fn split_with_rng<R: CryptoRng + RngCore + ?Sized>(
+    secret: &RistrettoScalar,
+    k: usize,
+    public_keys: &[RistrettoPoint],
+    rng: &mut R,
+) -> Result<(Vec<EncryptedShare>, Commitments), Error> {
+    validate_parameters::<RistrettoScalar>(k, public_keys.len())?;
+
+    let polynomial = Polynomial::random(*secret, k - 1, rng);
+    let commitments = Commitments::from_points(
+        polynomial
+            .coefficients()
+            .iter()
+            .map(|coefficient| blinding_generator() * coefficient.0)
+            .collect(),
+    );
+    let shares = public_keys
+        .iter()
+        .zip(1..)
+        .map(|(public_key, i)| {
+            let x = RistrettoScalar::from_u64(i);
+            let y = polynomial.evaluate(&x);
+            EncryptedShare::new(
+                x,
+                k,
+                *public_key,
+                public_key * y.0,
+                DleqProof::new(&y.0, (blinding_generator(), public_key), rng),
+            )
+        })
+        .collect();
+
+    Ok((shares, commitments))
+}
+
+/// Verifies an encrypted share against the commitments of its split.
+///
+/// Returns whether the share encrypts, to its public key,
+/// the value of the committed polynomial at its x-coordinate,
+/// which is false if the polynomial has more coefficients than the threshold of the share,
+/// as in [`crate::feldman::verify`].
+pub fn verify(share: &EncryptedShare, commitments: &Commitments) -> bool {
+    commitments.points().len() <= share.threshold
+        && share.proof.verify(
+            (blinding_generator(), &commitments.evaluate(&share.x)),
+            (&share.public_key, &share.encrypted),
+        )
+}
+
+/// Decrypts a share with the key pair of its participant,
+/// drawing the proof from the given random number generator.
+///
+/// ## Errors
+///
+/// Returns an error if the share is not encrypted to the public key of the key pair.
+pub fn decrypt<R: CryptoRng + RngCore + ?Sized>(
+    share: &EncryptedShare,
+    key_pair: &KeyPair,
+    rng: &mut R,
+) -> Result<DecryptedShare, Error> {
+    if share.public_key != key_pair.public {
+        return Err(Error::KeyMismatch);
+    }
+
+    // the secret key is never zero, hence invertible
+    let inverse = key_pair.secret.inverse().expect("nonzero secret key").0;
+    let decrypted = share.encrypted * inverse;
+    Ok(DecryptedShare::new(
+        share.x,
+        share.threshold,
+        decrypted,
+        DleqProof::new(
+            &key_pair.secret.0,
+            (&RISTRETTO_BASEPOINT_POINT, &decrypted),
+            rng,
+        ),
+    ))
+}
+
+/// Verifies a decrypted share against its encrypted share.
+///
+/// Returns whether the share is the decryption of the encrypted share
+/// under the secret key of its public key.
+pub fn verify_decryption(decrypted: &DecryptedShare, encrypted: &EncryptedShare) -> bool {
+    decrypted.x == encrypted.x
+        && decrypted.threshold == encrypted.threshold
+        && decrypted.proof.verify(
+            (&RISTRETTO_BASEPOINT_POINT, &encrypted.public_key),
+            (&decrypted.decrypted, &encrypted.encrypted),
+        )
+}
+
+/// Combines decrypted shares to reconstruct the point sG of the secret.
+///
+/// ## Errors
+///
+/// Returns an error if there are no shares,
+/// if they were split with different thresholds,
+/// if there are fewer shares than their threshold,
+/// or if an x-coordinate is zero or the same as another one.
+pub fn combine(shares: &[DecryptedShare]) -> Result<RistrettoPoint, Error> {
+    let Some(first) = shares.first() else {
+        return Err(Error::NoShares);
+    };
+    if let Some(share) = shares
+        .iter()
+        .find(|share| share.threshold != first.threshold)
+    {
+        return Err(Error::ThresholdMismatch {
+            expected: first.threshold,
+            found: share.threshold,
+        });
+    }
+    if shares.len() < first.threshold {
+        return Err(Error::InsufficientShares {
+            threshold: first.threshold,
+            shares: shares.len(),
+        });
+    }
+    let x: Vec<RistrettoScalar> = shares.iter().map(|share| share.x).collect();
+    validate_x_coordinates(&x)?;
+    let bases = Barycentric::new(&x)?.bases(&RistrettoScalar::zero());
+    Ok(shares
+        .iter()
+        .zip(bases)
+        .map(|(share, basis)| share.decrypted * basis.0)
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Splits 42 among five participants, returning their key pairs, shares and the commitments.
+    fn setup(k: usize) -> (Vec<KeyPair>, Vec<EncryptedShare>, Commitments) {
+        let mut rng = rand::thread_rng();
+        let key_pairs: Vec<KeyPair> = (0..5).map(|_| KeyPair::new(&mut rng)).collect();
+        let public_keys: Vec<RistrettoPoint> = key_pairs
+            .iter()
+            .map(|key_pair| *key_pair.public_key())
+            .collect();
+        let (shares, commitments) = split(&RistrettoScalar::from_u64(42), k, &public_keys).unwrap();
+        (key_pairs, shares, commitments)
+    }
+
+    #[test]
+    fn reconstructs_the_point_of_the_secret() {
+        let (key_pairs, shares, commitments) = setup(3);
+        assert!(shares.iter().all(|share| verify(share, &commitments)));
+        let decrypted: Vec<DecryptedShare> = shares
+            .iter()
+            .zip(&key_pairs)
+            .map(|(share, key_pair)| decrypt(share, key_pair, &mut rand::thread_rng()).unwrap())
+            .collect();
+        assert!(decrypted
+            .iter()
+            .zip(&shares)
+            .all(|(decrypted, share)| verify_decryption(decrypted, share)));
+        let point = RISTRETTO_BASEPOINT_POINT * RistrettoScalar::from_u64(42).0;
+        assert_eq!(combine(&decrypted[..3]).unwrap(), point);
+        assert_eq!(combine(&decrypted[2..]).unwrap(), point);
+        assert!(matches!(
+            combine(&decrypted[..2]),
+            Err(Error::InsufficientShares {
+                threshold: 3,
+                shares: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_tampered_shares() {
+        let (key_pairs, shares, commitments) = setup(3);
+        let mut tampered = shares[0].clone();
+        tampered.encrypted += RISTRETTO_BASEPOINT_POINT;
+        assert!(!verify(&tampered, &commitments));
+
+        let mut rng = rand::thread_rng();
+        let mut decrypted = decrypt(&shares[0], &key_pairs[0], &mut rng).unwrap();
+        decrypted.decrypted += RISTRETTO_BASEPOINT_POINT;
+        assert!(!verify_decryption(&decrypted, &shares[0]));
+        assert!(matches!(
+            decrypt(&shares[0], &key_pairs[1], &mut rng),
+            Err(Error::KeyMismatch)
+        ));
+    }
+
+    #[test]
+    fn verifies_shares_and_key_pairs_rebuilt_from_their_parts() {
+        let (key_pairs, shares, commitments) = setup(3);
+        let rebuilt: Vec<EncryptedShare> = shares
+            .iter()
+            .map(|share| {
+                let proof = share.proof();
+                EncryptedShare::new(
+                    *share.x(),
+                    share.threshold(),
+                    *share.public_key(),
+                    *share.encrypted(),
+                    DleqProof::from_parts(*proof.challenge(), *proof.response()),
+                )
+            })
+            .collect();
+        assert_eq!(rebuilt, shares);
+        assert!(rebuilt.iter().all(|share| verify(share, &commitments)));
+
+        let mut rng = rand::thread_rng();
+        let key_pair = KeyPair::from_secret_key(*key_pairs[0].secret_key()).unwrap();
+        assert_eq!(key_pair, key_pairs[0]);
+        let decrypted = decrypt(&rebuilt[0], &key_pair, &mut rng).unwrap();
+        let rebuilt = DecryptedShare::new(
+            *decrypted.x(),
+            decrypted.threshold(),
+            *decrypted.decrypted(),
+            *decrypted.proof(),
+        );
+        assert_eq!(rebuilt, decrypted);
+        assert!(verify_decryption(&rebuilt, &shares[0]));
+        assert!(matches!(
+            KeyPair::from_secret_key(RistrettoScalar::zero()),
+            Err(Error::ZeroSecretKey)
+        ));
+    }
+
+    #[test]
+    fn redacts_the_secret_key_from_debug_output() {
+        let key_pair = KeyPair::new(&mut rand::thread_rng());
+        let debug = format!("{key_pair:?}");
+        assert!(debug.contains(&format!("{:?}", key_pair.public_key())));
+        assert!(!debug.contains(&format!("{:?}", key_pair.secret_key())));
+        assert!(!debug.contains("secret"));
+    }
+
+    #[test]
+    fn rejects_commitments_above_the_threshold_degree() {
+        let (_, shares, commitments) = setup(4);
+        for mut share in shares {
+            share.threshold = 3;
+            assert!(!verify(&share, &commitments));
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Shamir's Secret Sharing over the real numbers.
+//!
+//! Shares are floating-point numbers, which makes them easy to plot
+//! but unsuitable for protecting real secrets:
+//! the charts use this module to illustrate the scheme,
+//! while [`crate::split`] and [`crate::combine`] work over GF(2⁸).
+//! Floating-point numbers are not a finite field, see [`crate::field`],
+//! hence the module has its own [`Polynomial`], subject to rounding.
+
+use rand::Rng;
+
+use crate::error::Error;
+use crate::validation::validate_threshold;
+
+/// A share of a secret.
+///
+/// A share is a point on the polynomial hiding the secret,
+/// along with the parameters of the split it belongs to
+/// and an optional label, such as the name of its holder.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Share {
+    x: f64,
+    y: f64,
+    threshold: usize,
+    total: usize,
+    label: Option<String>,
+}
+
+impl Share {
+    /// Creates a share of a split of `total` shares, any `threshold` of which reconstruct the secret.
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The x-coordinate of the share, never zero.
+    /// * `y` - The y-coordinate of the share.
+    /// * `threshold` - The number of shares needed to reconstruct the secret.
+    /// * `total` - The number of shares of the split.
+    pub fn new(x: f64, y: f64, threshold: usize, total: usize) -> Self {
+        Share {
+            x,
+            y,
+            threshold,
+            total,
+            label: None,
+        }
+    }
+
+    /// Labels the share, for example with the name of its holder.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Returns the x-coordinate of the share.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the y-coordinate of the share.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Returns the number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the number of shares of the split.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the label of the share, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+}
+
+/// A polynomial over the real numbers, as approximated by floating-point numbers.
+///
+/// The coefficients are stored from the constant term up,
+/// without trailing zeros, so that the zero polynomial has no coefficients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients, from the constant term up.
+    pub fn new(mut coefficients: Vec<f64>) -> Self {
+        while coefficients.last() == Some(&0.0) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+
+    /// Creates a polynomial with the given constant term,
+    /// whose `degree` other coefficients are drawn uniformly from `[-1, 1)`.
+    pub fn random<R: Rng + ?Sized>(constant: f64, degree: usize, rng: &mut R) -> Self {
+        Polynomial::new(
+            std::iter::once(constant)
+                .chain((0..degree).map(|_| rng.gen_range(-1.0..1.0)))
+                .collect(),
+        )
+    }
+
+    /// Returns the Lagrange interpolating polynomial through the given points,
+    /// of degree lower than the number of points.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if two points have the same x-coordinate.
+    pub fn interpolate(points: &[(f64, f64)]) -> Result<Self, Error> {
+        let mut coefficients = vec![0.0; points.len()];
+        for (j, &(x_j, y_j)) in points.iter().enumerate() {
+            // the basis is the product of (x - xₘ) over m ≠ j, divided by its value at xⱼ
+            let mut basis = vec![1.0];
+            let mut denominator = 1.0;
+            for (_, &(x_m, _)) in points.iter().enumerate().filter(|&(m, _)| m != j) {
+                basis.push(0.0);
+                for i in (1..basis.len()).rev() {
+                    basis[i] = basis[i - 1] - x_m * basis[i];
+                }
+                basis[0] *= -x_m;
+                denominator *= x_j - x_m;
+            }
+            if denominator == 0.0 {
+                return Err(Error::DuplicateShares);
+            }
+            let scale = y_j * denominator.recip();
+            for (coefficient, basis) in coefficients.iter_mut().zip(basis) {
+                *coefficient += basis * scale;
+            }
+        }
+        Ok(Polynomial::new(coefficients))
+    }
+
+    /// Returns the coefficients of the polynomial, from the constant term up.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's method.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |y, coefficient| y * x + coefficient)
+    }
+}
+
+/// A secret, the value of the polynomial at x = 0.
+pub type Secret = f64;
+
+/// Splits a secret into `n` shares, any `k` of which reconstruct it.
+///
+/// The coefficients of the polynomial, other than the secret,
+/// are drawn uniformly from `[-1, 1)`.
+/// The shares are evaluated at x = 1, 2, ..., n.
+///
+/// ## Arguments
+///
+/// * `secret` - The secret to split.
+/// * `k` - The number of shares needed to reconstruct the secret.
+/// * `n` - The number of shares to generate.
+///
+/// ## Errors
+///
+/// Returns an error if `k` is zero or greater than `n`,
+/// see [`validate_threshold`].
+pub fn split(secret: Secret, k: usize, n: usize) -> Result<Vec<Share>, Error> {
+    validate_threshold(k, n)?;
+
+    let polynomial = Polynomial::random(secret, k - 1, &mut rand::thread_rng());
+
+    let shares = (1..=n)
+        .map(|i| {
+            let x = i as f64;
+            Share::new(x, polynomial.evaluate(x), k, n)
+        })
+        .collect();
+
+    Ok(shares)
+}
+
+/// Combines shares to reconstruct the secret.
+///
+/// The secret is recovered by Lagrange interpolation of the shares at x = 0.
+/// At least `k` shares are needed to recover the secret of a threshold `k` split,
+/// fewer shares yield an unrelated value.
+///
+/// ## Errors
+///
+/// Returns an error if there are no shares.
+pub fn combine(shares: &[Share]) -> Result<Secret, Error> {
+    interpolate_at(shares, 0.0)
+}
+
+/// Evaluates the polynomial hidden by the shares at `x`.
+///
+/// The value is recovered by Lagrange interpolation of the shares at `x`,
+/// hence it is the secret at x = 0, and the y-coordinate
+/// of another share of the same split at any other x.
+///
+/// ## Errors
+///
+/// Returns an error if there are no shares.
+pub fn interpolate_at(shares: &[Share], x: f64) -> Result<f64, Error> {
+    if shares.is_empty() {
+        return Err(Error::NoShares);
+    }
+
+    let value = shares
+        .iter()
+        .enumerate()
+        .map(|(j, share_j)| {
+            let basis: f64 = shares
+                .iter()
+                .enumerate()
+                .filter(|&(m, _)| m != j)
+                .map(|(_, share_m)| (x - share_m.x) / (share_j.x - share_m.x))
+                .product();
+            share_j.y * basis
+        })
+        .sum();
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_approximately() {
+        let shares = split(42.0, 3, 5).unwrap();
+        for window in shares.windows(3) {
+            assert!((combine(window).unwrap() - 42.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn interpolates_through_the_points() {
+        let polynomial = Polynomial::interpolate(&[(1.0, 2.0), (2.0, 5.0), (3.0, 10.0)]).unwrap();
+        // 1 + x²
+        assert_eq!(polynomial.coefficients(), [1.0, 0.0, 1.0]);
+        assert_eq!(polynomial.evaluate(4.0), 17.0);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        assert!(matches!(split(42.0, 0, 5), Err(Error::ZeroThreshold)));
+        assert!(matches!(combine(&[]), Err(Error::NoShares)));
+    }
+}
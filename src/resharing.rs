@@ -0,0 +1,278 @@
+//! Resharing of a secret to a new threshold or set of participants, without a dealer.
+//!
+//! Instead of reconstructing the secret and splitting it again,
+//! `k` current shareholders each split their own share into a subshare
+//! for every new participant, with polynomials of the new degree `k' - 1`.
+//! Each new participant then aggregates the subshares it received,
+//! weighted by the Lagrange bases of the current shares at x = 0,
+//! into its share of the secret.
+//!
+//! The weighted sum of the polynomials of the subshares has the secret as constant term,
+//! hence the aggregated shares are the shares of a new split of the same secret,
+//! which no single participant (or machine) ever held.
+//! Every new participant must aggregate subshares of the same current shares.
+
+use rand::{CryptoRng, RngCore};
+
+use crate::barycentric::Barycentric;
+use crate::error::Error;
+use crate::field::Field;
+use crate::gf256::Gf256;
+use crate::validation::validate_x_coordinates;
+use crate::{split_elements, Share};
+
+/// The subshare of a current share sent to a new participant,
+/// along with the x-coordinate and the threshold of the current share.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Subshare<F = Gf256> {
+    from: F,
+    threshold: usize,
+    share: Share<F>,
+}
+
+impl<F> Subshare<F> {
+    /// Creates a subshare from its parts, such as the ones received from a current shareholder.
+    ///
+    /// ## Arguments
+    ///
+    /// * `from` - The x-coordinate of the current share the subshare was split from.
+    /// * `threshold` - The threshold of the current shares.
+    /// * `share` - The share of the current share, at the x-coordinate of its new participant.
+    pub fn new(from: F, threshold: usize, share: Share<F>) -> Self {
+        Subshare {
+            from,
+            threshold,
+            share,
+        }
+    }
+
+    /// Returns the x-coordinate of the current share the subshare was split from.
+    pub fn from(&self) -> &F {
+        &self.from
+    }
+
+    /// Returns the threshold of the current shares.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the share of the current share, at the x-coordinate of its new participant.
+    pub fn share(&self) -> &Share<F> {
+        &self.share
+    }
+}
+
+/// Splits a current share into `n` subshares, one for every new participant,
+/// any `k` of which will reconstruct the secret once aggregated.
+///
+/// The subshare at x = i must be sent to the i-th new participant.
+/// The subshares carry the fingerprint and the padding of the share, if any.
+///
+/// ## Errors
+///
+/// Returns an error if `k` is zero or greater than `n`,
+/// or if the field does not support `n` shares.
+pub fn reshare<F: Field>(share: &Share<F>, k: usize, n: usize) -> Result<Vec<Subshare<F>>, Error> {
+    reshare_with_rng(share, k, n, &mut rand::thread_rng())
+}
+
+/// Splits a current share into `n` subshares, one for every new participant,
+/// drawing the coefficients of the polynomials from the given random number generator.
+///
+/// See [`reshare`] for the details and errors.
+pub fn reshare_with_rng<F: Field, R: CryptoRng + RngCore + ?Sized>(
+    share: &Share<F>,
+    k: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<Subshare<F>>, Error> {
+    let subshares = split_elements(share.y(), k, n, rng)?
+        .into_iter()
+        .map(|mut subshare| {
+            subshare.fingerprint = share.fingerprint.clone();
+            subshare.padded = share.padded;
+            Subshare::new(share.x().clone(), share.threshold(), subshare)
+        })
+        .collect();
+    Ok(subshares)
+}
+
+/// Aggregates the subshares received by one new participant,
+/// one from every current share, into its new share of the secret.
+///
+/// ## Errors
+///
+/// Returns an error if there are no subshares,
+/// if there are fewer subshares than the threshold of the current shares,
+/// if two subshares come from the same current share or from shares at x = 0,
+/// if the subshares have different x-coordinates or lengths,
+/// or if they were split with different thresholds or numbers of shares.
+pub fn aggregate<F: Field>(subshares: &[Subshare<F>]) -> Result<Share<F>, Error> {
+    let Some(first) = subshares.first() else {
+        return Err(Error::NoShares);
+    };
+    if let Some(subshare) = subshares
+        .iter()
+        .find(|subshare| subshare.threshold != first.threshold)
+    {
+        return Err(Error::ThresholdMismatch {
+            expected: first.threshold,
+            found: subshare.threshold,
+        });
+    }
+    if subshares.len() < first.threshold {
+        return Err(Error::InsufficientShares {
+            threshold: first.threshold,
+            shares: subshares.len(),
+        });
+    }
+    if let Some(subshare) = subshares
+        .iter()
+        .find(|subshare| subshare.share.threshold() != first.share.threshold())
+    {
+        return Err(Error::ThresholdMismatch {
+            expected: first.share.threshold(),
+            found: subshare.share.threshold(),
+        });
+    }
+    if let Some(subshare) = subshares
+        .iter()
+        .find(|subshare| subshare.share.total() != first.share.total())
+    {
+        return Err(Error::MixedShareSets {
+            expected: first.share.total(),
+            found: subshare.share.total(),
+        });
+    }
+    if subshares
+        .iter()
+        .any(|subshare| subshare.share.x() != first.share.x())
+    {
+        return Err(Error::XCoordinateMismatch);
+    }
+    if subshares
+        .iter()
+        .any(|subshare| subshare.share.y().len() != first.share.y().len())
+    {
+        return Err(Error::LengthMismatch);
+    }
+
+    let from: Vec<F> = subshares
+        .iter()
+        .map(|subshare| subshare.from.clone())
+        .collect();
+    validate_x_coordinates(&from)?;
+    let bases = Barycentric::new(&from)?.bases(&F::zero());
+
+    let y = (0..first.share.y().len())
+        .map(|i| {
+            subshares
+                .iter()
+                .zip(&bases)
+                .fold(F::zero(), |sum, (subshare, basis)| {
+                    sum + subshare.share.y()[i].clone() * basis.clone()
+                })
+        })
+        .collect();
+
+    let mut share = Share::new(
+        first.share.x().clone(),
+        y,
+        first.share.threshold(),
+        first.share.total(),
+    );
+    share.fingerprint = first.share.fingerprint.clone();
+    share.padded = first.share.padded;
+    Ok(share)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combine, split};
+
+    /// Reshares the given current shares, returning the new shares of every participant.
+    fn reshare_all(shares: &[Share], k: usize, n: usize) -> Vec<Share> {
+        let subshares: Vec<Vec<Subshare>> = shares
+            .iter()
+            .map(|share| reshare(share, k, n).unwrap())
+            .collect();
+        (0..n)
+            .map(|i| {
+                let received: Vec<Subshare> = subshares
+                    .iter()
+                    .map(|subshares| subshares[i].clone())
+                    .collect();
+                aggregate(&received).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reshares_to_a_new_threshold() {
+        let shares = split(b"secret", 3, 5).unwrap();
+        let reshared = reshare_all(&shares[1..4], 2, 4);
+        assert_eq!(reshared[0].threshold(), 2);
+        assert_eq!(combine(&reshared[2..]).unwrap(), b"secret");
+        assert_ne!(reshared[0].y(), shares[0].y());
+    }
+
+    #[test]
+    fn rejects_fewer_subshares_than_the_old_threshold() {
+        let shares = split(b"secret", 3, 5).unwrap();
+        let received: Vec<Subshare> = shares[..2]
+            .iter()
+            .map(|share| reshare(share, 2, 4).unwrap().remove(0))
+            .collect();
+        assert!(matches!(
+            aggregate(&received),
+            Err(Error::InsufficientShares {
+                threshold: 3,
+                shares: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_subshares_of_splits_with_different_numbers_of_shares() {
+        let shares = split(b"secret", 3, 5).unwrap();
+        let mut received: Vec<Subshare> = shares[..2]
+            .iter()
+            .map(|share| reshare(share, 2, 4).unwrap().remove(0))
+            .collect();
+        received.push(reshare(&shares[2], 2, 5).unwrap().remove(0));
+        assert!(matches!(
+            aggregate(&received),
+            Err(Error::MixedShareSets {
+                expected: 4,
+                found: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn aggregates_subshares_rebuilt_from_their_parts() {
+        let shares = split(b"secret", 3, 5).unwrap();
+        let subshares: Vec<Vec<Subshare>> = shares[..3]
+            .iter()
+            .map(|share| reshare(share, 2, 4).unwrap())
+            .collect();
+        let reshared: Vec<Share> = (0..2)
+            .map(|i| {
+                let received: Vec<Subshare> = subshares
+                    .iter()
+                    .map(|subshares| {
+                        let subshare = &subshares[i];
+                        Subshare::new(
+                            *subshare.from(),
+                            subshare.threshold(),
+                            subshare.share().clone(),
+                        )
+                    })
+                    .collect();
+                aggregate(&received).unwrap()
+            })
+            .collect();
+        assert_eq!(combine(&reshared).unwrap(), b"secret");
+    }
+}
@@ -0,0 +1,126 @@
+//! The scalar field of the Ristretto group.
+//!
+//! Verifiable secret sharing commits to the coefficients of the polynomial
+//! in a group where discrete logarithms are hard, the Ristretto group over Curve25519,
+//! hence the polynomial is defined over its scalar field,
+//! the prime field of order ℓ = 2²⁵² + 27742317777372353535851937790883648493.
+//!
+//! This module requires the `vss` feature.
+
+// Division multiplies by the inverse.
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use curve25519_dalek::Scalar;
+use rand::Rng;
+
+use crate::field::Field;
+
+/// An element of the scalar field of the Ristretto group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RistrettoScalar(pub Scalar);
+
+impl From<Scalar> for RistrettoScalar {
+    fn from(scalar: Scalar) -> Self {
+        RistrettoScalar(scalar)
+    }
+}
+
+impl fmt::Display for RistrettoScalar {
+    /// Formats the little-endian bytes of the canonical representative in hexadecimal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0
+            .as_bytes()
+            .iter()
+            .try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl Add for RistrettoScalar {
+    type Output = RistrettoScalar;
+
+    fn add(self, rhs: RistrettoScalar) -> RistrettoScalar {
+        RistrettoScalar(self.0 + rhs.0)
+    }
+}
+
+impl Sub for RistrettoScalar {
+    type Output = RistrettoScalar;
+
+    fn sub(self, rhs: RistrettoScalar) -> RistrettoScalar {
+        RistrettoScalar(self.0 - rhs.0)
+    }
+}
+
+impl Neg for RistrettoScalar {
+    type Output = RistrettoScalar;
+
+    fn neg(self) -> RistrettoScalar {
+        RistrettoScalar(-self.0)
+    }
+}
+
+impl Mul for RistrettoScalar {
+    type Output = RistrettoScalar;
+
+    fn mul(self, rhs: RistrettoScalar) -> RistrettoScalar {
+        RistrettoScalar(self.0 * rhs.0)
+    }
+}
+
+impl Div for RistrettoScalar {
+    type Output = RistrettoScalar;
+
+    /// Divides two elements.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: RistrettoScalar) -> RistrettoScalar {
+        self * rhs
+            .inverse()
+            .expect("division by zero in the Ristretto scalar field")
+    }
+}
+
+impl Field for RistrettoScalar {
+    const MAX_SHARES: u64 = u64::MAX;
+
+    fn zero() -> Self {
+        RistrettoScalar(Scalar::ZERO)
+    }
+
+    fn one() -> Self {
+        RistrettoScalar(Scalar::ONE)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.0 == Scalar::ZERO {
+            None
+        } else {
+            Some(RistrettoScalar(self.0.invert()))
+        }
+    }
+
+    /// Reduces 64 random bytes modulo ℓ, whose bias is negligible.
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = [0; 64];
+        rng.fill_bytes(&mut bytes);
+        RistrettoScalar(Scalar::from_bytes_mod_order_wide(&bytes))
+    }
+
+    fn from_u64(value: u64) -> Self {
+        RistrettoScalar(Scalar::from(value))
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        let (low, high) = self.0.as_bytes().split_at(8);
+        if high.iter().all(|&byte| byte == 0) {
+            Some(u64::from_le_bytes(low.try_into().ok()?))
+        } else {
+            None
+        }
+    }
+}
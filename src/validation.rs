@@ -0,0 +1,238 @@
+//! Validation of the parameters of a split and of the shares to combine.
+//!
+//! Every way of splitting a secret, from [`crate::split`] to the [`crate::builder`],
+//! checks its parameters with the functions of this module before generating any share,
+//! so that an invalid split is rejected with the same [`Error`] whichever API is used.
+//! Likewise, shares are checked for consistency before being combined,
+//! rather than silently reconstructing a wrong secret.
+
+use crate::error::Error;
+use crate::field::Field;
+use crate::Share;
+
+/// Checks that `n` shares with a threshold of `k` can be generated over the field `F`.
+///
+/// ## Errors
+///
+/// Returns an error if `k` is zero, if `k` is greater than `n`,
+/// or if `n` is greater than [`Field::MAX_SHARES`],
+/// the number of non-zero elements of the field available as x-coordinates.
+pub fn validate_parameters<F: Field>(k: usize, n: usize) -> Result<(), Error> {
+    validate_threshold(k, n)?;
+    if n as u64 > F::MAX_SHARES {
+        return Err(Error::TooManyShares {
+            shares: n,
+            max: F::MAX_SHARES,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that `n` shares with a threshold of `k` can be generated, whatever the field.
+///
+/// ## Errors
+///
+/// Returns an error if `k` is zero, or if `k` is greater than `n`.
+pub fn validate_threshold(k: usize, n: usize) -> Result<(), Error> {
+    if k == 0 {
+        return Err(Error::ZeroThreshold);
+    }
+    if k > n {
+        return Err(Error::ThresholdAboveShares {
+            threshold: k,
+            shares: n,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that every byte of a secret can be split over the field `F`.
+///
+/// ## Errors
+///
+/// Returns an error if a byte is not mapped back to itself by the field,
+/// such as in a prime field with fewer than 256 elements,
+/// since it would be reconstructed as another byte.
+pub fn validate_byte_field<F: Field>() -> Result<(), Error> {
+    let holds_bytes = (0..=u8::MAX).all(|byte| {
+        let byte = u64::from(byte);
+        F::from_u64(byte).to_u64() == Some(byte)
+    });
+    if !holds_bytes {
+        return Err(Error::FieldTooSmall);
+    }
+    Ok(())
+}
+
+/// Checks that a secret can be split.
+///
+/// ## Errors
+///
+/// Returns an error if the secret is empty,
+/// since its shares would hold no y-coordinate.
+pub fn validate_secret(secret: &[u8]) -> Result<(), Error> {
+    if secret.is_empty() {
+        return Err(Error::EmptySecret);
+    }
+    Ok(())
+}
+
+/// Checks that a secret can be padded to a multiple of `block_size` bytes.
+///
+/// ## Errors
+///
+/// Returns an error if `block_size` is zero.
+pub fn validate_block_size(block_size: usize) -> Result<(), Error> {
+    if block_size == 0 {
+        return Err(Error::ZeroBlockSize);
+    }
+    Ok(())
+}
+
+/// Checks that shares can be evaluated at the given x-coordinates.
+///
+/// ## Errors
+///
+/// Returns an error if an x-coordinate is zero,
+/// since the share would be the secret itself,
+/// or if two x-coordinates are the same.
+pub fn validate_x_coordinates<F: Field>(x: &[F]) -> Result<(), Error> {
+    for (j, x_j) in x.iter().enumerate() {
+        if *x_j == F::zero() {
+            return Err(Error::ZeroXCoordinate);
+        }
+        if x[..j].contains(x_j) {
+            return Err(Error::DuplicateShares);
+        }
+    }
+    Ok(())
+}
+
+/// Checks that shares can be combined, as shares of the same split.
+///
+/// Shares of distinct splits with the same parameters cannot be told apart,
+/// and still reconstruct a wrong secret.
+///
+/// ## Errors
+///
+/// Returns an error if there are no shares,
+/// if a share has the x-coordinate zero, which a forged share could use
+/// to be taken for the secret, or two shares have the same x-coordinate,
+/// if the shares hold no y-coordinate or have different lengths,
+/// if they were split with different thresholds or numbers of shares,
+/// if they carry different fingerprints of the secret or paddings,
+/// or if there are fewer shares than their threshold,
+/// which would reconstruct an unrelated secret.
+pub fn validate_shares<F: Field>(shares: &[Share<F>]) -> Result<(), Error> {
+    let Some(first) = shares.first() else {
+        return Err(Error::NoShares);
+    };
+    let x: Vec<F> = shares.iter().map(|share| share.x().clone()).collect();
+    validate_x_coordinates(&x)?;
+    if first.y().is_empty() {
+        return Err(Error::EmptyShares);
+    }
+    for share in shares {
+        if share.y().len() != first.y().len() {
+            return Err(Error::LengthMismatch);
+        }
+        if share.threshold() != first.threshold() {
+            return Err(Error::ThresholdMismatch {
+                expected: first.threshold(),
+                found: share.threshold(),
+            });
+        }
+        if share.total() != first.total() {
+            return Err(Error::MixedShareSets {
+                expected: first.total(),
+                found: share.total(),
+            });
+        }
+        if share.fingerprint() != first.fingerprint() {
+            return Err(Error::FingerprintMismatch);
+        }
+        if share.is_padded() != first.is_padded() {
+            return Err(Error::PaddingMismatch);
+        }
+    }
+    if shares.len() < first.threshold() {
+        return Err(Error::InsufficientShares {
+            threshold: first.threshold(),
+            shares: shares.len(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gf256::Gf256;
+    use crate::prime::{Fp, Fp257};
+    use crate::split;
+
+    #[test]
+    fn checks_the_parameters_against_the_field() {
+        assert!(validate_parameters::<Gf256>(255, 255).is_ok());
+        assert!(matches!(
+            validate_parameters::<Gf256>(2, 256),
+            Err(Error::TooManyShares { .. })
+        ));
+        assert!(matches!(
+            validate_parameters::<Gf256>(0, 2),
+            Err(Error::ZeroThreshold)
+        ));
+        assert!(matches!(
+            validate_parameters::<Gf256>(3, 2),
+            Err(Error::ThresholdAboveShares {
+                threshold: 3,
+                shares: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn checks_that_the_field_holds_every_byte() {
+        assert!(validate_byte_field::<Gf256>().is_ok());
+        assert!(validate_byte_field::<Fp257>().is_ok());
+        assert!(matches!(
+            validate_byte_field::<Fp<251>>(),
+            Err(Error::FieldTooSmall)
+        ));
+    }
+
+    #[test]
+    fn rejects_shares_of_different_lengths_or_paddings() {
+        let shares = split(b"abc", 2, 3).unwrap();
+        let other = split(b"abcd", 2, 3).unwrap();
+        assert!(matches!(
+            validate_shares(&[shares[0].clone(), other[1].clone()]),
+            Err(Error::LengthMismatch)
+        ));
+        assert!(matches!(
+            validate_shares(&[shares[0].clone().with_padding(), shares[1].clone()]),
+            Err(Error::PaddingMismatch)
+        ));
+        assert!(validate_shares(&shares[1..]).is_ok());
+    }
+
+    #[test]
+    fn rejects_shares_at_zero_or_without_y_coordinates() {
+        let shares = split(b"abc", 2, 3).unwrap();
+        let forged = Share::new(Gf256(0), shares[0].y().to_vec(), 2, 3);
+        assert!(matches!(
+            validate_shares(&[forged, shares[1].clone()]),
+            Err(Error::ZeroXCoordinate)
+        ));
+        let empty: Vec<Share> = (1..=2)
+            .map(|x| Share::new(Gf256(x), Vec::new(), 2, 3))
+            .collect();
+        assert!(matches!(validate_shares(&empty), Err(Error::EmptyShares)));
+    }
+
+    #[test]
+    fn rejects_empty_secrets() {
+        assert!(validate_secret(b"secret").is_ok());
+        assert!(matches!(validate_secret(b""), Err(Error::EmptySecret)));
+    }
+}